@@ -1,383 +1,3457 @@
 use crate::types::{
-    CachedCiVariables, CachedIssues, CachedMemberships, CachedPipelineSchedules,
-    CachedProjectMetadata, ExportStatus, Membership, SourceIssue, SourceMember,
-    SourcePipelineSchedule, SourceProject, SourceUser, SourceVariable,
+    AccessTokenVariableReference, BoardRepair, CachedAccessTokens, CachedApprovals, CachedBadges,
+    CachedBoards, CachedCiVariables, CachedEnvironments, CachedEpics, CachedGroupLabels,
+    CachedGroupMilestones, CachedIntegrations, CachedIssues, CachedMemberships,
+    CachedMergeRequests, CachedPipelineSchedules, CachedProjectMetadata, CachedRunners,
+    CachedSnippets, CachedTriggers, CachedUserKeys, CachedUsers, CreatedAccessToken, CreatedRunner,
+    ExportStatus, GraphqlTimelog, IntegrationSecrets, Issuable, Membership, ProjectSettings,
+    RejectedProjectSetting, RepositoryVerification, SourceAccessToken, SourceBadge, SourceBoard,
+    SourceEpic, SourceGroup, SourceIntegration, SourceIssue, SourceLabel, SourceMember,
+    SourceMergeRequest, SourceMilestone, SourcePackage, SourcePipelineSchedule, SourceProject,
+    SourceProjectApprovals, SourceProjectEnvironments, SourceRunner, SourceSnippet, SourceTrigger,
+    SourceUser, SourceUserKeys, SourceVariable, TargetAccessToken, TargetEpic, TargetMembership,
+    TargetProject, TargetUser, Timelog, TriggerTokenMapping, UnmappedApprovalRule,
+    UnmappedContributor, UnmappedDeployAccessLevel, UnrestoredTimelog, UserIdentity,
 };
-use crate::{gitlab, http};
+use crate::{archive, crypto, env, gitlab, http, registry};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::io::Write;
 
 // ---------------------------------------------------------------------------
-// Create Target CI Variables
+// Sync Target User States
 // ---------------------------------------------------------------------------
-pub async fn create_target_ci_variables() -> Result<(), Box<dyn Error>> {
-    let variables = std::fs::read_to_string("cache/ci_variables.json")?;
-    let variables: CachedCiVariables = serde_json::from_str(&variables)?;
+// Blocked, banned and deactivated users cannot be impersonated, so this runs
+// after every stage that acts on behalf of the migrated users.
+pub async fn sync_target_user_states() -> Result<(), Box<dyn Error>> {
+    let profiles = load_source_users()?;
+    let users = fetch_target_users_by_source_username().await?;
+
+    let mut failed = vec![];
+    for (username, profile) in profiles.iter().sorted_by_key(|(username, _)| *username) {
+        let (action, state) = match profile.state.as_str() {
+            "blocked" | "ldap_blocked" | "blocked_pending_approval" => ("block", "blocked"),
+            "banned" => ("ban", "banned"),
+            "deactivated" => ("deactivate", "deactivated"),
+            _ => continue,
+        };
+        let user = match users.get(username) {
+            Some(user) if !profile.bot && user.state != state => user,
+            _ => continue,
+        };
+        if !gitlab::update_target_user_state(user, action).await? {
+            failed.push(username.to_owned());
+        }
+    }
+
+    if !failed.is_empty() {
+        println!(
+            "Could not update the state of the following users:\n{:#?}",
+            failed
+        );
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Access Tokens
+// ---------------------------------------------------------------------------
+pub async fn create_target_access_tokens() -> Result<(), Box<dyn Error>> {
+    let access_tokens = std::fs::read_to_string("cache/access_tokens.json")?;
+    let mut access_tokens: CachedAccessTokens = serde_json::from_str(&access_tokens)?;
 
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
+        .into_iter()
+        .map(|group| (group.key(), group))
+        .collect();
     let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
         .await?
         .into_iter()
         .map(|project| (project.key(), project))
         .collect();
 
-    let futures: Vec<_> = variables
+    let group_tokens = access_tokens.remove("groups").unwrap_or_default();
+    let project_tokens = access_tokens.remove("projects").unwrap_or_default();
+    let group_memberships = group_tokens.iter().filter_map(|(key, tokens)| {
+        groups
+            .get(key)
+            .map(|group| (TargetMembership::Group(group.clone()), tokens.clone()))
+    });
+    let project_memberships = project_tokens.iter().filter_map(|(key, tokens)| {
+        projects
+            .get(key)
+            .map(|project| (TargetMembership::Project(project.clone()), tokens.clone()))
+    });
+
+    let futures: Vec<_> = group_memberships
+        .chain(project_memberships)
+        .filter(|(_, tokens)| tokens.iter().any(|token| token.active))
+        .map(|(membership, tokens)| create_target_membership_access_tokens(membership, tokens))
+        .collect();
+    let created = http::politely_try_join_all(futures, 8, 500).await?;
+
+    let mut all_created = load_created_access_tokens()?;
+    for (key, mut tokens) in created {
+        all_created.entry(key).or_default().append(&mut tokens);
+    }
+    save_created_access_tokens(&all_created)?;
+
+    let references = find_access_token_variable_references(&group_tokens, &project_tokens)?;
+    save_access_token_variable_references(&references)?;
+    Ok(())
+}
+
+async fn create_target_membership_access_tokens(
+    membership: TargetMembership,
+    tokens: Vec<SourceAccessToken>,
+) -> Result<(String, Vec<CreatedAccessToken>), Box<dyn Error>> {
+    let existing_names: Vec<_> = gitlab::fetch_all_target_access_tokens(&membership)
+        .await?
         .into_iter()
-        .flat_map(|(key, vars)| {
-            let pairs: Vec<_> = vars.into_iter().map(|v| (key.to_owned(), v)).collect();
-            pairs
-        })
-        .filter_map(|(key, var)| {
-            let project_option = projects.get(&key);
-            project_option.map(|project| gitlab::create_target_ci_variable(var, project))
-        })
+        .filter(|token| token.active)
+        .map(|token| token.name)
         .collect();
-    http::politely_try_join_all(futures, 8, 500).await?;
 
+    let mut created = vec![];
+    for token in tokens {
+        if !token.active || existing_names.contains(&token.name) {
+            continue;
+        }
+        let result = gitlab::create_target_access_token(&token, &membership).await?;
+        if let Some(value) = result.and_then(|x| x.token) {
+            created.push(CreatedAccessToken {
+                name: token.name,
+                scopes: token.scopes,
+                access_level: token.access_level,
+                expires_at: token.expires_at,
+                token: value,
+            });
+        }
+    }
+    Ok((membership.key(), created))
+}
+
+fn find_access_token_variable_references(
+    group_tokens: &HashMap<String, Vec<SourceAccessToken>>,
+    project_tokens: &HashMap<String, Vec<SourceAccessToken>>,
+) -> Result<Vec<AccessTokenVariableReference>, Box<dyn Error>> {
+    let variables = std::fs::read_to_string("cache/ci_variables.json")?;
+    let variables: CachedCiVariables = serde_json::from_str(&variables)?;
+
+    let memberships = std::fs::read_to_string("cache/memberships.json")?;
+    let memberships: CachedMemberships = serde_json::from_str(&memberships)?;
+    let usernames: HashMap<_, _> = memberships
+        .values()
+        .flat_map(|members| members.values())
+        .flatten()
+        .map(|member| (member.id, member.username.to_string()))
+        .collect();
+
+    let mut references = vec![];
+    for (project_key, project_variables) in variables {
+        let tokens: Vec<_> = group_tokens
+            .iter()
+            .filter(|(group_key, _)| project_key.starts_with(&format!("{}/", group_key)))
+            .chain(project_tokens.get_key_value(&project_key))
+            .flat_map(|(_, tokens)| tokens)
+            .collect();
+        for variable in project_variables {
+            let token_names: Vec<_> = tokens
+                .iter()
+                .filter(|token| {
+                    let bot_username = usernames.get(&token.user_id);
+                    bot_username.is_some_and(|x| variable.value.contains(x))
+                })
+                .map(|token| token.name.to_string())
+                .unique()
+                .collect();
+            // Token values can't be read back from the source, so a PAT-looking
+            // value that no inventoried token matches is reported on its own.
+            let unknown_token = token_names.is_empty() && variable.value.starts_with("glpat-");
+            if !token_names.is_empty() || unknown_token {
+                references.push(AccessTokenVariableReference {
+                    project: project_key.to_string(),
+                    variable_key: variable.key,
+                    token_names,
+                    unknown_token,
+                });
+            }
+        }
+    }
+    Ok(references)
+}
+
+fn load_created_access_tokens() -> Result<HashMap<String, Vec<CreatedAccessToken>>, Box<dyn Error>>
+{
+    let json_path = "cache/created_access_tokens.json";
+    if !std::path::Path::new(json_path).exists() {
+        return Ok(HashMap::new());
+    }
+    let created = std::fs::read_to_string(json_path)?;
+    Ok(serde_json::from_str(&created)?)
+}
+
+fn save_created_access_tokens(
+    created: &HashMap<String, Vec<CreatedAccessToken>>,
+) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/created_access_tokens.json", dir_path);
+    serde_json::to_writer_pretty(&create_protected_file(&json_path)?, &created)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+fn save_access_token_variable_references(
+    references: &[AccessTokenVariableReference],
+) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/access_token_variable_references.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &references)?;
+    println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
+fn create_protected_file(path: &str) -> Result<std::fs::File, Box<dyn Error>> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let file = options.open(path)?;
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    Ok(file)
+}
+
 // ---------------------------------------------------------------------------
-// Reassign Target Issues
+// Create Target Group Labels
 // ---------------------------------------------------------------------------
-pub async fn reassign_target_issues() -> Result<(), Box<dyn Error>> {
-    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+pub async fn create_target_group_labels() -> Result<(), Box<dyn Error>> {
+    let all_labels = std::fs::read_to_string("cache/group_labels.json")?;
+    let all_labels: CachedGroupLabels = serde_json::from_str(&all_labels)?;
+
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
         .await?
         .into_iter()
-        .filter(|project| !project.archived)
-        .map(|project| (project.key(), project))
+        .map(|group| (group.key(), group))
         .collect();
 
-    let users: HashMap<_, _> = gitlab::fetch_all_target_users()
+    for (key, labels) in all_labels {
+        let group = match groups.get(&key) {
+            Some(group) => group,
+            None => {
+                println!("Skipping labels for missing group {}...", key);
+                continue;
+            }
+        };
+        let existing_names: Vec<_> = gitlab::fetch_all_target_group_labels(group)
+            .await?
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+        let futures: Vec<_> = labels
+            .into_iter()
+            .filter(|label| !existing_names.contains(&label.name))
+            .map(|label| gitlab::create_target_group_label(label, group))
+            .collect();
+        http::politely_try_join_all(futures, 8, 500).await?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Group Milestones
+// ---------------------------------------------------------------------------
+pub async fn create_target_group_milestones() -> Result<(), Box<dyn Error>> {
+    let all_milestones = std::fs::read_to_string("cache/group_milestones.json")?;
+    let all_milestones: CachedGroupMilestones = serde_json::from_str(&all_milestones)?;
+
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
         .await?
         .into_iter()
-        .map(|user| (user.key(), user))
+        .map(|group| (group.key(), group))
         .collect();
 
-    let all_issues = std::fs::read_to_string("cache/issues.json")?;
-    let all_issues: CachedIssues = serde_json::from_str(&all_issues)?;
+    for (key, milestones) in all_milestones {
+        let group = match groups.get(&key) {
+            Some(group) => group,
+            None => {
+                println!("Skipping milestones for missing group {}...", key);
+                continue;
+            }
+        };
+        let existing_titles: Vec<_> = gitlab::fetch_all_target_group_milestones(group)
+            .await?
+            .into_iter()
+            .map(|milestone| milestone.title)
+            .collect();
+        let futures: Vec<_> = milestones
+            .into_iter()
+            .filter(|milestone| !existing_titles.contains(&milestone.title))
+            .map(|milestone| gitlab::create_target_group_milestone(milestone, group))
+            .collect();
+        http::politely_try_join_all(futures, 8, 500).await?;
+    }
+    Ok(())
+}
 
-    let futures: Vec<_> = all_issues
+// ---------------------------------------------------------------------------
+// Create Target Epics
+// ---------------------------------------------------------------------------
+pub async fn create_target_epics() -> Result<(), Box<dyn Error>> {
+    let all_epics = std::fs::read_to_string("cache/epics.json")?;
+    let all_epics: CachedEpics = serde_json::from_str(&all_epics)?;
+
+    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
+    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
+
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
         .into_iter()
-        .flat_map(|(key, issues)| match projects.get(&key) {
-            Some(project) => {
-                let pairs: Vec<_> = issues
-                    .into_iter()
-                    .filter_map(|issue| {
-                        let assignee_username = issue
-                            .assignee
-                            .as_ref()
-                            .map(|x| x.username.to_owned())
-                            .unwrap_or_default();
-                        let target_user_option = users.get(&assignee_username);
-                        target_user_option
-                            .map(|user| gitlab::reassign_target_issue(issue, project, user))
-                    })
-                    .collect();
-                pairs
+        .map(|group| (group.key(), group))
+        .collect();
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let mut created: HashMap<u32, TargetEpic> = HashMap::new();
+    let mut remaining = vec![];
+    let mut source_epics = vec![];
+    for (key, epics) in all_epics {
+        let group = match groups.get(&key) {
+            Some(group) => group,
+            None => {
+                println!("Skipping epics for missing group {}...", key);
+                continue;
             }
-            None => vec![],
+        };
+        let existing: HashMap<_, _> = gitlab::fetch_all_target_epics(group)
+            .await?
+            .into_iter()
+            .map(|epic| ((epic.title.to_string(), epic.created_at.to_string()), epic))
+            .collect();
+        for epic in epics {
+            match existing.get(&(epic.title.to_string(), epic.created_at.to_string())) {
+                Some(target_epic) => {
+                    created.insert(epic.id, target_epic.clone());
+                }
+                None => remaining.push((group.clone(), epic.clone())),
+            }
+            source_epics.push(epic);
+        }
+    }
+
+    let source_ids: Vec<_> = source_epics.iter().map(|epic| epic.id).collect();
+    while !remaining.is_empty() {
+        let (ready, pending): (Vec<_>, Vec<_>) =
+            remaining
+                .into_iter()
+                .partition(|(_, epic)| match epic.parent_id {
+                    Some(parent_id) => {
+                        created.contains_key(&parent_id) || !source_ids.contains(&parent_id)
+                    }
+                    None => true,
+                });
+        if ready.is_empty() {
+            println!(
+                "Skipping {} epics whose parents could not be created...",
+                pending.len()
+            );
+            break;
+        }
+        let futures: Vec<_> = ready
+            .into_iter()
+            .map(|(group, epic)| {
+                let parent = epic.parent_id.and_then(|id| created.get(&id)).cloned();
+                async move { gitlab::create_target_epic(epic, &group, parent.as_ref()).await }
+            })
+            .collect();
+        let results = http::politely_try_join_all(futures, 8, 500).await?;
+        created.extend(results.into_iter().flatten());
+        remaining = pending;
+    }
+
+    let futures: Vec<_> = source_epics
+        .iter()
+        .filter_map(|epic| created.get(&epic.id).map(|target_epic| (epic, target_epic)))
+        .flat_map(|(epic, target_epic)| {
+            let links: Vec<_> = epic
+                .issues
+                .iter()
+                .filter_map(|issue| {
+                    let source_project = metadata.get(&issue.project_id)?;
+                    let project = projects.get(&source_project.key())?;
+                    Some(gitlab::link_target_epic_issue(
+                        target_epic,
+                        project,
+                        issue.iid,
+                    ))
+                })
+                .collect();
+            links
         })
         .collect();
-    http::politely_try_join_all(futures, 24, 500).await?;
+    http::politely_try_join_all(futures, 8, 500).await?;
 
     Ok(())
 }
 
 // ---------------------------------------------------------------------------
-// Delete Target Pipeline Schedules
+// Create Target Badges
 // ---------------------------------------------------------------------------
-pub async fn delete_target_pipeline_schedules() -> Result<(), Box<dyn Error>> {
-    let projects: Vec<_> = gitlab::fetch_all_target_projects()
+pub async fn create_target_badges() -> Result<(), Box<dyn Error>> {
+    let badges = std::fs::read_to_string("cache/badges.json")?;
+    let mut badges: CachedBadges = serde_json::from_str(&badges)?;
+
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
+        .into_iter()
+        .map(|group| (group.key(), group))
+        .collect();
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
         .await?
         .into_iter()
+        .map(|project| (project.key(), project))
         .collect();
 
-    let futures: Vec<_> = projects
-        .iter()
-        .map(gitlab::delete_target_pipeline_schedules)
+    let group_badges = badges.remove("groups").unwrap_or_default();
+    let project_badges = badges.remove("projects").unwrap_or_default();
+    let group_memberships = group_badges.into_iter().filter_map(|(key, badges)| {
+        groups
+            .get(&key)
+            .map(|group| (TargetMembership::Group(group.clone()), badges))
+    });
+    let project_memberships = project_badges.into_iter().filter_map(|(key, badges)| {
+        projects
+            .get(&key)
+            .map(|project| (TargetMembership::Project(project.clone()), badges))
+    });
+
+    let futures: Vec<_> = group_memberships
+        .chain(project_memberships)
+        .filter(|(_, badges)| !badges.is_empty())
+        .map(|(membership, badges)| create_target_membership_badges(membership, badges))
         .collect();
-    http::politely_try_join_all(futures, 24, 500).await?;
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
 
+async fn create_target_membership_badges(
+    membership: TargetMembership,
+    badges: Vec<SourceBadge>,
+) -> Result<(), Box<dyn Error>> {
+    let existing: Vec<_> = gitlab::fetch_all_target_badges(&membership)
+        .await?
+        .into_iter()
+        .map(|badge| (badge.link_url, badge.image_url))
+        .collect();
+    for mut badge in badges {
+        badge.link_url = gitlab::rewrite_source_url(&badge.link_url);
+        badge.image_url = gitlab::rewrite_source_url(&badge.image_url);
+        let pair = (badge.link_url.to_owned(), badge.image_url.to_owned());
+        if !existing.contains(&pair) {
+            gitlab::create_target_badge(badge, &membership).await?;
+        }
+    }
     Ok(())
 }
 
 // ---------------------------------------------------------------------------
-// Create Target Pipeline Schedules
+// Create Target Snippets
 // ---------------------------------------------------------------------------
-pub async fn create_target_pipeline_schedules() -> Result<(), Box<dyn Error>> {
+pub async fn create_target_snippets() -> Result<(), Box<dyn Error>> {
+    let snippets = std::fs::read_to_string("cache/snippets.json")?;
+    let mut snippets: CachedSnippets = serde_json::from_str(&snippets)?;
+
     let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
         .await?
         .into_iter()
         .map(|project| (project.key(), project))
         .collect();
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
 
-    let all_schedules = std::fs::read_to_string("cache/pipeline_schedules.json")?;
-    let all_schedules: CachedPipelineSchedules = serde_json::from_str(&all_schedules)?;
-
-    let futures: Vec<_> = all_schedules
+    let project_snippets = snippets.remove("projects").unwrap_or_default();
+    let futures: Vec<_> = project_snippets
         .into_iter()
-        .filter_map(|(key, schedules)| {
+        .filter(|(_, snippets)| !snippets.is_empty())
+        .filter_map(|(key, snippets)| {
             projects
                 .get(&key)
-                .map(|project| gitlab::create_target_pipeline_schedules(schedules, project))
+                .map(|project| create_target_project_snippets(project, snippets, &users))
         })
         .collect();
-    http::politely_try_join_all(futures, 24, 500).await?;
+    http::politely_try_join_all(futures, 8, 500).await?;
+
+    let personal_snippets = snippets.remove("users").unwrap_or_default();
+    let mut futures = vec![];
+    for (username, snippets) in personal_snippets {
+        match users.get(&username) {
+            Some(user) => futures.push(create_target_personal_snippets(user, snippets)),
+            None => println!(
+                "Skipping {} personal snippets of missing user {}...",
+                snippets.len(),
+                username
+            ),
+        }
+    }
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+async fn create_target_project_snippets(
+    project: &TargetProject,
+    snippets: Vec<SourceSnippet>,
+    users: &HashMap<String, TargetUser>,
+) -> Result<(), Box<dyn Error>> {
+    let existing: Vec<_> = gitlab::fetch_all_target_project_snippets(project)
+        .await?
+        .into_iter()
+        .map(|snippet| snippet.title)
+        .collect();
+    let snippets_by_author = snippets
+        .into_iter()
+        .filter(|snippet| !existing.contains(&snippet.title))
+        .into_group_map_by(|snippet| snippet.author.username.to_owned());
+    for (username, snippets) in snippets_by_author {
+        let user = match users.get(&username) {
+            Some(user) => user,
+            None => {
+                println!(
+                    "Skipping {} snippets of missing user {} in {}...",
+                    snippets.len(),
+                    username,
+                    project.key()
+                );
+                continue;
+            }
+        };
+        let token = match impersonate_target_user(user).await {
+            Some(token) => token,
+            None => continue,
+        };
+        let mut result = Ok(());
+        for snippet in &snippets {
+            result = gitlab::create_target_project_snippet(snippet, project, &token).await;
+            if result.is_err() {
+                break;
+            }
+        }
+        gitlab::revoke_target_impersonation_token(user, &token).await?;
+        result?;
+    }
+    Ok(())
+}
+
+async fn create_target_personal_snippets(
+    user: &TargetUser,
+    snippets: Vec<SourceSnippet>,
+) -> Result<(), Box<dyn Error>> {
+    let token = match impersonate_target_user(user).await {
+        Some(token) => token,
+        None => return Ok(()),
+    };
+    let result = create_target_personal_snippets_as(user, snippets, &token).await;
+    gitlab::revoke_target_impersonation_token(user, &token).await?;
+    result
+}
 
+async fn create_target_personal_snippets_as(
+    user: &TargetUser,
+    snippets: Vec<SourceSnippet>,
+    token: &TargetAccessToken,
+) -> Result<(), Box<dyn Error>> {
+    let existing: Vec<_> = gitlab::fetch_all_target_personal_snippets(token)
+        .await?
+        .into_iter()
+        .map(|snippet| snippet.title)
+        .collect();
+    for snippet in snippets {
+        if !existing.contains(&snippet.title) {
+            gitlab::create_target_personal_snippet(&snippet, user, token).await?;
+        }
+    }
     Ok(())
 }
 
+async fn impersonate_target_user(user: &TargetUser) -> Option<TargetAccessToken> {
+    match gitlab::create_target_impersonation_token(user).await {
+        Ok(token) => Some(token),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!("Context: \nimpersonating\n{}\n__________", user.key());
+            None
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
-// Add Target Users to Projects
+// Create Target Approval Rules
 // ---------------------------------------------------------------------------
-pub async fn add_target_users_to_projects() -> Result<(), Box<dyn Error>> {
-    let projects = gitlab::fetch_all_target_projects().await?;
-    let project_ids: HashMap<_, _> = projects
+pub async fn create_target_approval_rules() -> Result<(), Box<dyn Error>> {
+    let all_approvals = std::fs::read_to_string("cache/approvals.json")?;
+    let all_approvals: CachedApprovals = serde_json::from_str(&all_approvals)?;
+
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
         .into_iter()
         .map(|project| (project.key(), project))
         .collect();
-
-    let users = gitlab::fetch_all_target_users().await?;
-    let user_ids: HashMap<_, _> = users
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
         .into_iter()
-        .map(|user| (user.username.clone(), user))
+        .map(|group| (group.key(), group))
         .collect();
 
-    let memberships = std::fs::read_to_string("cache/memberships.json")?;
-    let mut memberships: CachedMemberships = serde_json::from_str(&memberships)?;
-    let project_memberships = memberships.remove("projects").unwrap_or_default();
+    let mut unmapped = vec![];
+    for (key, approvals) in all_approvals {
+        let project = match projects.get(&key) {
+            Some(project) => project,
+            None => continue,
+        };
+        if let Some(settings) = approvals.settings {
+            gitlab::update_target_approval_settings(settings, project).await?;
+        }
+        if approvals.rules.is_empty() {
+            continue;
+        }
 
-    let futures: Vec<_> = project_memberships
-        .into_iter()
-        .flat_map(|(project_key, members)| {
-            members
-                .into_iter()
-                .map(move |member| (project_key.clone(), member))
-        })
-        .filter_map(|(project_key, member)| {
-            let project_option = project_ids.get(&project_key);
-            let user_option = user_ids.get(&member.username);
-            match (project_option, user_option) {
-                (Some(project), Some(user)) => Some(gitlab::add_target_project_member_to_project(
-                    project.clone(),
-                    user.clone(),
-                    member,
-                )),
-                _ => None,
+        let existing_names: Vec<_> = gitlab::fetch_all_target_approval_rules(project)
+            .await?
+            .into_iter()
+            .map(|rule| rule.name)
+            .collect();
+        let branches: HashMap<_, _> = gitlab::fetch_all_target_protected_branches(project)
+            .await?
+            .into_iter()
+            .map(|branch| (branch.name.to_string(), branch))
+            .collect();
+        for rule in approvals.rules {
+            if existing_names.contains(&rule.name) {
+                continue;
             }
-        })
-        .collect();
-    http::politely_try_join_all(futures, 8, 500).await?;
+            // Report approver and code owner rules are managed by GitLab itself.
+            if rule.rule_type != "regular" && rule.rule_type != "any_approver" {
+                println!(
+                    "Skipping {} approval rule '{}' in {}...",
+                    rule.rule_type, rule.name, key
+                );
+                unmapped.push(UnmappedApprovalRule {
+                    project: key.to_string(),
+                    rule_name: rule.name.to_string(),
+                    rule_type: rule.rule_type.to_string(),
+                    skipped: true,
+                    unmapped_users: vec![],
+                    unmapped_groups: vec![],
+                    unmapped_branches: vec![],
+                });
+                continue;
+            }
+            let (target_users, unmapped_users): (Vec<_>, Vec<_>) = rule
+                .users
+                .iter()
+                .partition(|user| users.contains_key(&user.username));
+            let (target_groups, unmapped_groups): (Vec<_>, Vec<_>) = rule
+                .groups
+                .iter()
+                .partition(|group| groups.contains_key(&group.key()));
+            let (target_branches, unmapped_branches): (Vec<_>, Vec<_>) = rule
+                .protected_branches
+                .iter()
+                .partition(|branch| branches.contains_key(&branch.name));
+            // A branch-scoped rule without any of its branches would apply to
+            // every branch of the project.
+            let skipped = !rule.protected_branches.is_empty() && target_branches.is_empty();
+            if !unmapped_users.is_empty()
+                || !unmapped_groups.is_empty()
+                || !unmapped_branches.is_empty()
+            {
+                unmapped.push(UnmappedApprovalRule {
+                    project: key.to_string(),
+                    rule_name: rule.name.to_string(),
+                    rule_type: rule.rule_type.to_string(),
+                    skipped,
+                    unmapped_users: unmapped_users
+                        .iter()
+                        .map(|user| user.username.to_string())
+                        .collect(),
+                    unmapped_groups: unmapped_groups.iter().map(|group| group.key()).collect(),
+                    unmapped_branches: unmapped_branches
+                        .iter()
+                        .map(|branch| branch.name.to_string())
+                        .collect(),
+                });
+            }
+            if skipped {
+                println!(
+                    "Skipping approval rule '{}' in {} without protected branches...",
+                    rule.name, key
+                );
+                continue;
+            }
+            let target_users = target_users
+                .iter()
+                .filter_map(|user| users.get(&user.username))
+                .cloned()
+                .collect();
+            let target_groups = target_groups
+                .iter()
+                .filter_map(|group| groups.get(&group.key()))
+                .cloned()
+                .collect();
+            let target_branches = target_branches
+                .iter()
+                .filter_map(|branch| branches.get(&branch.name))
+                .cloned()
+                .collect();
+            gitlab::create_target_approval_rule(
+                rule,
+                project,
+                target_users,
+                target_groups,
+                target_branches,
+            )
+            .await?;
+        }
+        http::throttle_for_ms(500);
+    }
+
+    println!(
+        "Found {} approval rules that could not be fully mapped!",
+        unmapped.len()
+    );
+    save_unmapped_approval_rules(&unmapped)?;
+    Ok(())
+}
+
+fn save_unmapped_approval_rules(unmapped: &[UnmappedApprovalRule]) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/unmapped_approval_rules.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &unmapped)?;
+    println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
 // ---------------------------------------------------------------------------
-// Add Target Users to Groups
+// Create Target Environments
+// ---------------------------------------------------------------------------
+pub async fn create_target_environments() -> Result<(), Box<dyn Error>> {
+    let all_environments = std::fs::read_to_string("cache/environments.json")?;
+    let all_environments: CachedEnvironments = serde_json::from_str(&all_environments)?;
+
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
+        .into_iter()
+        .map(|group| (group.key(), group))
+        .collect();
+
+    let mut unmapped = vec![];
+    for (key, environments) in all_environments {
+        let project = match projects.get(&key) {
+            Some(project) => project,
+            None => continue,
+        };
+
+        let existing_names: Vec<_> = gitlab::fetch_all_target_environments(project)
+            .await?
+            .into_iter()
+            .map(|environment| environment.name)
+            .collect();
+        for environment in environments.environments {
+            if !existing_names.contains(&environment.name) {
+                gitlab::create_target_environment(environment, project).await?;
+            }
+        }
+
+        if !environments.protected_environments.is_empty() {
+            let protected_names: Vec<_> = gitlab::fetch_all_target_protected_environments(project)
+                .await?
+                .into_iter()
+                .map(|environment| environment.name)
+                .collect();
+            for environment in environments.protected_environments {
+                if protected_names.contains(&environment.name) {
+                    continue;
+                }
+                let mut deploy_access_levels = vec![];
+                let num_unmapped = unmapped.len();
+                for level in &environment.deploy_access_levels {
+                    let mapped = if level.user_id.is_some() {
+                        let user = level.username.as_ref().and_then(|x| users.get(x));
+                        user.map(|x| serde_json::json!({ "user_id": x.id }))
+                    } else if level.group_id.is_some() {
+                        let group = level.group_full_path.as_ref().and_then(|x| groups.get(x));
+                        group.map(|x| serde_json::json!({ "group_id": x.id }))
+                    } else {
+                        let access_level = level.access_level;
+                        access_level.map(|x| serde_json::json!({ "access_level": x }))
+                    };
+                    match mapped {
+                        Some(x) => deploy_access_levels.push(x),
+                        None => unmapped.push(UnmappedDeployAccessLevel {
+                            project: key.to_string(),
+                            environment: environment.name.to_string(),
+                            username: level.username.clone(),
+                            group_full_path: level.group_full_path.clone(),
+                            fallback_access_level: None,
+                        }),
+                    }
+                }
+                // Without any deployer the environment would stay unprotected,
+                // so maintainers are allowed to deploy instead.
+                if deploy_access_levels.is_empty() {
+                    println!(
+                        "Falling back to maintainers as deployers of {} in {}...",
+                        environment.name, key
+                    );
+                    deploy_access_levels.push(serde_json::json!({ "access_level": 40 }));
+                    if unmapped.len() == num_unmapped {
+                        unmapped.push(UnmappedDeployAccessLevel {
+                            project: key.to_string(),
+                            environment: environment.name.to_string(),
+                            username: None,
+                            group_full_path: None,
+                            fallback_access_level: None,
+                        });
+                    }
+                    for level in &mut unmapped[num_unmapped..] {
+                        level.fallback_access_level = Some(40);
+                    }
+                }
+                gitlab::create_target_protected_environment(
+                    &environment,
+                    deploy_access_levels,
+                    project,
+                )
+                .await?;
+            }
+        }
+
+        if !environments.freeze_periods.is_empty() {
+            let existing_periods: Vec<_> = gitlab::fetch_all_target_freeze_periods(project)
+                .await?
+                .into_iter()
+                .map(|x| (x.freeze_start, x.freeze_end, x.cron_timezone))
+                .collect();
+            for freeze_period in environments.freeze_periods {
+                let period = (
+                    freeze_period.freeze_start.to_owned(),
+                    freeze_period.freeze_end.to_owned(),
+                    freeze_period.cron_timezone.to_owned(),
+                );
+                if !existing_periods.contains(&period) {
+                    gitlab::create_target_freeze_period(freeze_period, project).await?;
+                }
+            }
+        }
+        http::throttle_for_ms(500);
+    }
+
+    println!(
+        "Found {} deployers that could not be mapped!",
+        unmapped.len()
+    );
+    save_unmapped_deploy_access_levels(&unmapped)?;
+    Ok(())
+}
+
+fn save_unmapped_deploy_access_levels(
+    unmapped: &[UnmappedDeployAccessLevel],
+) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/unmapped_deployers.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &unmapped)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Copy Target Container Images
+// ---------------------------------------------------------------------------
+pub async fn copy_target_container_images() -> Result<(), Box<dyn Error>> {
+    let target_paths: Vec<_> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| project.path_with_namespace)
+        .collect();
+
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let projects: Vec<_> = gitlab::fetch_all_source_projects(groups)
+        .await?
+        .into_iter()
+        .filter(|project| target_paths.contains(&project.path_with_namespace))
+        .collect();
+
+    let mut num_copied = 0;
+    let mut num_up_to_date = 0;
+    let mut failed = vec![];
+    for project in projects {
+        let repositories = gitlab::fetch_all_source_registry_repositories(&project).await?;
+        for repository in repositories {
+            let tags = match gitlab::fetch_all_source_registry_tags(&project, &repository).await {
+                Ok(tags) => tags,
+                Err(err) => {
+                    println!("Failed to list tags of {}\n{}", repository.location, err);
+                    failed.push(repository.location);
+                    continue;
+                }
+            };
+            for tag in tags {
+                match copy_container_image(repository.path.to_owned(), tag.name.to_owned()).await {
+                    Ok(true) => num_copied += 1,
+                    Ok(false) => num_up_to_date += 1,
+                    Err(_) => failed.push(tag.location),
+                }
+            }
+        }
+    }
+
+    println!(
+        "Copied {} images, {} were already up to date.",
+        num_copied, num_up_to_date
+    );
+    if !failed.is_empty() {
+        println!(
+            "Failed to copy the following images, run this app again to retry:\n{:#?}",
+            failed
+        );
+    }
+    Ok(())
+}
+
+async fn copy_container_image(repository: String, tag: String) -> Result<bool, String> {
+    let spawn_result =
+        tokio::task::spawn_blocking(move || match registry::copy_image(&repository, &tag) {
+            Ok(x) => Ok(x),
+            Err(err) => {
+                println!("Failed to copy {}:{}\n{}", repository, tag, err);
+                Err("Failed to copy container image!".to_owned())
+            }
+        })
+        .await;
+    spawn_result.map_err(|_| "Spawn blocking failed!".to_string())?
+}
+
+// ---------------------------------------------------------------------------
+// Copy Target Packages
+// ---------------------------------------------------------------------------
+const SUPPORTED_PACKAGE_TYPES: [&str; 3] = ["generic", "npm", "maven"];
+
+pub async fn copy_target_packages() -> Result<(), Box<dyn Error>> {
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let source_projects = gitlab::fetch_all_source_projects(groups).await?;
+
+    let mut failed = vec![];
+    for source_project in source_projects {
+        let project = match projects.get(&source_project.key()) {
+            Some(project) => project,
+            None => continue,
+        };
+        let packages = gitlab::fetch_all_source_packages(&source_project).await?;
+        if packages.is_empty() {
+            continue;
+        }
+        let existing_keys: Vec<_> = gitlab::fetch_all_target_packages(project)
+            .await?
+            .into_iter()
+            .map(|package| package.key())
+            .collect();
+
+        for package in packages {
+            if existing_keys.contains(&package.key()) {
+                println!("Skipping existing package {}...", package.key());
+                continue;
+            }
+            if !SUPPORTED_PACKAGE_TYPES.contains(&package.package_type.as_str()) {
+                println!("Skipping unsupported package {}...", package.key());
+                continue;
+            }
+            let result = copy_target_package(&source_project, &package, project).await;
+            if let Err(err) = result {
+                println!("Failed to copy {}: {}", package.key(), err);
+                failed.push(format!("{} in {}", package.key(), project.key()));
+            }
+        }
+        http::throttle_for_ms(500);
+    }
+
+    if !failed.is_empty() {
+        println!(
+            "Failed to copy the following packages, run this app again to retry:\n{:#?}",
+            failed
+        );
+    }
+    Ok(())
+}
+
+async fn copy_target_package(
+    source_project: &SourceProject,
+    package: &SourcePackage,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    let files = gitlab::fetch_all_source_package_files(source_project, package).await?;
+    let dir_path = format!("cache/packages/{}/{}", source_project.id, package.id);
+    std::fs::create_dir_all(&dir_path)?;
+
+    let mut downloaded = vec![];
+    for file in files
+        .into_iter()
+        .unique_by(|file| file.file_name.to_owned())
+    {
+        let file_path = format!("{}/{}", dir_path, file.file_name);
+        if !std::path::Path::new(&file_path).exists() {
+            println!("Downloading {} of {}...", file.file_name, package.key());
+            let mut response =
+                gitlab::download_source_package_file(source_project, package, &file.file_name)
+                    .await?;
+            let partial_path = format!("{}.partial", file_path);
+            let mut output = std::fs::File::create(&partial_path)?;
+            while let Some(chunk) = response.chunk().await? {
+                output.write_all(&chunk)?;
+            }
+            std::fs::rename(partial_path, &file_path)?;
+        }
+        downloaded.push((file.file_name, file_path));
+    }
+
+    if package.package_type == "npm" {
+        let (metadata, dist_tags) =
+            gitlab::fetch_source_npm_version_metadata(source_project, package).await?;
+        let (file_name, file_path) = downloaded
+            .into_iter()
+            .find(|(file_name, _)| file_name.ends_with(".tgz"))
+            .ok_or("Missing npm tarball!")?;
+        let content = std::fs::read(file_path)?;
+        gitlab::publish_target_npm_package(
+            package, metadata, &dist_tags, &file_name, content, project,
+        )
+        .await?;
+    } else {
+        for (file_name, file_path) in downloaded {
+            gitlab::upload_target_package_file(package, &file_name, &file_path, project).await?;
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Verify Target Repositories
+// ---------------------------------------------------------------------------
+pub async fn verify_target_repositories() -> Result<(), Box<dyn Error>> {
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = gitlab::fetch_all_source_projects(groups)
+        .await?
+        .into_iter()
+        .filter_map(|source_project| {
+            projects
+                .get(&source_project.key())
+                .map(|project| verify_target_repository(source_project, project))
+        })
+        .collect();
+    let mut verifications = http::politely_try_join_all(futures, 8, 500).await?;
+    verifications.sort_by(|x, y| x.project.cmp(&y.project));
+
+    println!(
+        "{:<60} {:<6} {:>16} {:>24} {:>24}",
+        "PROJECT", "RESULT", "COMMITS", "REPOSITORY SIZE", "LFS SIZE"
+    );
+    for x in &verifications {
+        println!(
+            "{:<60} {:<6} {:>16} {:>24} {:>24}",
+            x.project,
+            match (x.passed, &x.error) {
+                (true, _) => "PASS",
+                (false, None) => "FAIL",
+                (false, Some(_)) => "ERROR",
+            },
+            format!("{}/{}", x.source_commit_count, x.target_commit_count),
+            format!("{}/{}", x.source_repository_size, x.target_repository_size),
+            format!(
+                "{}/{}",
+                x.source_lfs_objects_size, x.target_lfs_objects_size
+            ),
+        );
+    }
+    let num_failed = verifications.iter().filter(|x| !x.passed).count();
+    println!(
+        "{} of {} projects failed verification!",
+        num_failed,
+        verifications.len()
+    );
+    save_repository_verifications(&verifications)?;
+    Ok(())
+}
+
+async fn verify_target_repository(
+    source_project: SourceProject,
+    project: &TargetProject,
+) -> Result<RepositoryVerification, Box<dyn Error>> {
+    println!("Verifying repository of {}...", project.key());
+    match compare_target_repository(source_project, project).await {
+        Ok(verification) => Ok(verification),
+        Err(err) => {
+            println!("Failed to verify {}\n{}", project.key(), err);
+            Ok(RepositoryVerification {
+                project: project.key(),
+                error: Some(err.to_string()),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+async fn compare_target_repository(
+    source_project: SourceProject,
+    project: &TargetProject,
+) -> Result<RepositoryVerification, Box<dyn Error>> {
+    let source_statistics = gitlab::fetch_source_project_statistics(&source_project).await?;
+    let target_statistics = gitlab::fetch_target_project_statistics(project).await?;
+
+    let mut mismatched_refs = vec![];
+    for kind in ["branches", "tags"] {
+        let source_refs = gitlab::fetch_all_source_repository_refs(&source_project, kind).await?;
+        let target_heads: HashMap<_, _> = gitlab::fetch_all_target_repository_refs(project, kind)
+            .await?
+            .into_iter()
+            .map(|x| (x.name, x.commit.id))
+            .collect();
+        let mismatched: Vec<_> = source_refs
+            .into_iter()
+            .filter(|x| target_heads.get(&x.name) != Some(&x.commit.id))
+            .map(|x| x.name)
+            .collect();
+        mismatched_refs.push(mismatched);
+    }
+    let mismatched_tags = mismatched_refs.pop().unwrap_or_default();
+    let mismatched_branches = mismatched_refs.pop().unwrap_or_default();
+
+    let source_size = source_statistics.repository_size as f64;
+    let target_size = target_statistics.repository_size as f64;
+    let is_size_similar = (source_size - target_size).abs() <= source_size * 0.1;
+    let passed = mismatched_branches.is_empty()
+        && mismatched_tags.is_empty()
+        && source_statistics.commit_count == target_statistics.commit_count
+        && source_statistics.lfs_objects_size == target_statistics.lfs_objects_size
+        && is_size_similar;
+
+    Ok(RepositoryVerification {
+        project: project.key(),
+        passed,
+        error: None,
+        mismatched_branches,
+        mismatched_tags,
+        source_commit_count: source_statistics.commit_count,
+        target_commit_count: target_statistics.commit_count,
+        source_repository_size: source_statistics.repository_size,
+        target_repository_size: target_statistics.repository_size,
+        source_lfs_objects_size: source_statistics.lfs_objects_size,
+        target_lfs_objects_size: target_statistics.lfs_objects_size,
+    })
+}
+
+fn save_repository_verifications(
+    verifications: &[RepositoryVerification],
+) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/repository_verifications.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &verifications)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Sync Target Project Settings
+// ---------------------------------------------------------------------------
+pub async fn sync_target_project_settings() -> Result<(), Box<dyn Error>> {
+    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
+    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
+
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let futures: Vec<_> = metadata
+        .into_values()
+        .filter_map(|source_project| {
+            projects
+                .get(&source_project.key())
+                .map(|project| sync_target_project_setting(*source_project.settings, project))
+        })
+        .collect();
+    let mut num_synced = 0;
+    let mut rejected = vec![];
+    for (synced, mut project_rejected) in http::politely_try_join_all(futures, 8, 500).await? {
+        if synced {
+            num_synced += 1;
+        }
+        rejected.append(&mut project_rejected);
+    }
+    println!("Updated the settings of {} projects.", num_synced);
+    save_rejected_project_settings(&rejected)?;
+    Ok(())
+}
+
+async fn sync_target_project_setting(
+    settings: ProjectSettings,
+    project: &TargetProject,
+) -> Result<(bool, Vec<RejectedProjectSetting>), Box<dyn Error>> {
+    let target_settings =
+        serde_json::to_value(gitlab::fetch_target_project_settings(project).await?)?;
+    let mut changes = serde_json::Map::new();
+    if let serde_json::Value::Object(settings) = serde_json::to_value(settings)? {
+        for (key, value) in settings {
+            if value.is_null() || target_settings.get(&key) == Some(&value) {
+                continue;
+            }
+            println!(
+                "Setting {} of {} from {} to {}...",
+                key,
+                project.key(),
+                target_settings[&key],
+                value
+            );
+            // The project API reads the policy from a differently named key.
+            let key = match key.as_str() {
+                "container_expiration_policy" => {
+                    "container_expiration_policy_attributes".to_string()
+                }
+                _ => key,
+            };
+            changes.insert(key, value);
+        }
+    }
+    if changes.is_empty() {
+        return Ok((false, vec![]));
+    }
+    if gitlab::update_target_project_settings(project, &changes).await? {
+        return Ok((true, vec![]));
+    }
+
+    // A single rejected setting fails the whole update, so the settings are
+    // retried one by one to apply the others.
+    let mut synced = false;
+    let mut rejected = vec![];
+    for (key, value) in changes {
+        let change = serde_json::Map::from_iter([(key.to_owned(), value.clone())]);
+        if gitlab::update_target_project_settings(project, &change).await? {
+            synced = true;
+        } else {
+            rejected.push(RejectedProjectSetting {
+                project: project.key(),
+                setting: key,
+                value,
+            });
+        }
+    }
+    Ok((synced, rejected))
+}
+
+fn save_rejected_project_settings(
+    rejected: &[RejectedProjectSetting],
+) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/rejected_project_settings.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &rejected)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target CI Variables
+// ---------------------------------------------------------------------------
+pub async fn create_target_ci_variables() -> Result<(), Box<dyn Error>> {
+    let variables = std::fs::read_to_string("cache/ci_variables.json")?;
+    let variables: CachedCiVariables = serde_json::from_str(&variables)?;
+
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let futures: Vec<_> = variables
+        .into_iter()
+        .flat_map(|(key, vars)| {
+            let pairs: Vec<_> = vars.into_iter().map(|v| (key.to_owned(), v)).collect();
+            pairs
+        })
+        .filter_map(|(key, var)| {
+            let project_option = projects.get(&key);
+            project_option.map(|project| gitlab::create_target_ci_variable(var, project))
+        })
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Triggers
+// ---------------------------------------------------------------------------
+pub async fn create_target_triggers() -> Result<(), Box<dyn Error>> {
+    let passphrase = env::load_env("TRIGGER_TOKEN_PASSPHRASE");
+    if passphrase.is_empty() {
+        return Err("TRIGGER_TOKEN_PASSPHRASE must be set to encrypt the token mapping!".into());
+    }
+    let mut mappings = load_trigger_token_mappings(&passphrase)?;
+
+    let triggers = std::fs::read_to_string("cache/triggers.json")?;
+    let triggers: CachedTriggers = serde_json::from_str(&triggers)?;
+
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+    let fallback_owner = env::load_env("TRIGGER_FALLBACK_OWNER");
+    let fallback_owner = users.get(&fallback_owner);
+
+    let futures: Vec<_> = triggers
+        .into_iter()
+        .filter(|(_, triggers)| !triggers.is_empty())
+        .filter_map(|(key, triggers)| {
+            projects.get(&key).map(|project| {
+                create_target_project_triggers(triggers, project, &users, fallback_owner)
+            })
+        })
+        .collect();
+    let mut skipped = vec![];
+    for (created, mut project_skipped) in http::politely_try_join_all(futures, 8, 500).await? {
+        mappings.extend(created);
+        skipped.append(&mut project_skipped);
+    }
+    save_trigger_token_mappings(&mappings, &passphrase)?;
+
+    if !skipped.is_empty() {
+        println!(
+            "Skipped the following triggers without an owner to impersonate:\n{:#?}",
+            skipped
+        );
+    }
+    Ok(())
+}
+
+async fn create_target_project_triggers(
+    triggers: Vec<SourceTrigger>,
+    project: &TargetProject,
+    users: &HashMap<String, TargetUser>,
+    fallback_owner: Option<&TargetUser>,
+) -> Result<(Vec<TriggerTokenMapping>, Vec<String>), Box<dyn Error>> {
+    let existing: Vec<_> = gitlab::fetch_all_target_triggers(project)
+        .await?
+        .into_iter()
+        .map(|trigger| trigger.description)
+        .collect();
+    let mut mappings = vec![];
+    let mut skipped = vec![];
+    for trigger in triggers {
+        if existing.contains(&trigger.description) {
+            continue;
+        }
+        let owner = trigger
+            .owner
+            .as_ref()
+            .and_then(|owner| users.get(&owner.username));
+        // Triggered pipelines run as the trigger's owner, so a trigger is never
+        // created with the administrator token.
+        let (owner, token) = match impersonate_owner(owner, fallback_owner).await {
+            Some(x) => x,
+            None => {
+                skipped.push(format!("{} in {}", trigger.description, project.key()));
+                continue;
+            }
+        };
+        let private_token = token.token.to_owned().unwrap_or_default();
+
+        let created = gitlab::create_target_trigger(&trigger, project, &private_token).await;
+        gitlab::revoke_target_impersonation_token(owner, &token).await?;
+        if let Some(created) = created? {
+            mappings.push(TriggerTokenMapping {
+                project: project.key(),
+                description: trigger.description,
+                source_token: trigger.token,
+                target_token: created.token,
+            });
+        }
+    }
+    Ok((mappings, skipped))
+}
+
+fn load_trigger_token_mappings(
+    passphrase: &str,
+) -> Result<Vec<TriggerTokenMapping>, Box<dyn Error>> {
+    let path = "cache/trigger_tokens.json.enc";
+    if !std::path::Path::new(path).exists() {
+        return Ok(vec![]);
+    }
+    let encrypted = std::fs::read(path)?;
+    let mappings = crypto::decrypt(&encrypted, passphrase)?;
+    Ok(serde_json::from_slice(&mappings)?)
+}
+
+fn save_trigger_token_mappings(
+    mappings: &[TriggerTokenMapping],
+    passphrase: &str,
+) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let enc_path = format!("{}/trigger_tokens.json.enc", dir_path);
+    let mappings = serde_json::to_vec_pretty(mappings)?;
+    let mut file = create_protected_file(&enc_path)?;
+    file.write_all(&crypto::encrypt(&mappings, passphrase)?)?;
+    println!("Successfully wrote to {}!", enc_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Repair Target Boards
+// ---------------------------------------------------------------------------
+pub async fn repair_target_boards() -> Result<(), Box<dyn Error>> {
+    let boards = std::fs::read_to_string("cache/boards.json")?;
+    let mut boards: CachedBoards = serde_json::from_str(&boards)?;
+
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
+        .into_iter()
+        .map(|group| (group.key(), group))
+        .collect();
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+    let users: HashMap<_, _> = fetch_target_users_by_source_username()
+        .await?
+        .into_iter()
+        .map(|(username, user)| (username, user.id))
+        .collect();
+
+    let group_boards = boards.remove("groups").unwrap_or_default();
+    let project_boards = boards.remove("projects").unwrap_or_default();
+    let group_memberships = group_boards.into_iter().filter_map(|(key, boards)| {
+        groups
+            .get(&key)
+            .map(|group| (TargetMembership::Group(group.clone()), boards))
+    });
+    let project_memberships = project_boards.into_iter().filter_map(|(key, boards)| {
+        projects
+            .get(&key)
+            .map(|project| (TargetMembership::Project(project.clone()), boards))
+    });
+
+    let futures: Vec<_> = group_memberships
+        .chain(project_memberships)
+        .filter(|(_, boards)| !boards.is_empty())
+        .map(|(membership, boards)| repair_target_membership_boards(membership, boards, &users))
+        .collect();
+    let repairs: Vec<_> = http::politely_try_join_all(futures, 8, 500)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let num_unmapped = repairs
+        .iter()
+        .filter(|x| !x.unmapped_lists.is_empty())
+        .count();
+    println!(
+        "Repaired {} boards, {} of which still miss lists!",
+        repairs.len(),
+        num_unmapped
+    );
+    save_board_repairs(&repairs)?;
+    Ok(())
+}
+
+async fn repair_target_membership_boards(
+    membership: TargetMembership,
+    boards: Vec<SourceBoard>,
+    users: &HashMap<String, u32>,
+) -> Result<Vec<BoardRepair>, Box<dyn Error>> {
+    let existing = gitlab::fetch_all_target_boards(&membership).await?;
+    let labels: HashMap<_, _> = gitlab::fetch_all_target_labels(&membership)
+        .await?
+        .into_iter()
+        .map(|label| (label.name, label.id))
+        .collect();
+    let milestones: HashMap<_, _> = gitlab::fetch_all_target_milestones(&membership)
+        .await?
+        .into_iter()
+        .map(|milestone| (milestone.title, milestone.id))
+        .collect();
+
+    let mut repairs = vec![];
+    for board in boards {
+        let mut repair = BoardRepair {
+            scope: membership.key(),
+            board: board.name.to_owned(),
+            created_board: false,
+            updated_scope: false,
+            created_lists: vec![],
+            unmapped_lists: vec![],
+        };
+        let target_board = match existing.iter().find(|x| x.name == board.name) {
+            Some(target_board) => target_board.clone(),
+            None => match gitlab::create_target_board(&board, &membership).await? {
+                Some(created) => {
+                    repair.created_board = true;
+                    created
+                }
+                None => continue,
+            },
+        };
+
+        let assignee_id = board
+            .assignee
+            .as_ref()
+            .and_then(|x| users.get(&x.username))
+            .copied();
+        let milestone_id = board
+            .milestone
+            .as_ref()
+            .and_then(|x| milestones.get(&x.title))
+            .copied();
+        let source_labels: Vec<_> = board.labels.iter().map(|x| &x.name).sorted().collect();
+        let target_labels: Vec<_> = target_board
+            .labels
+            .iter()
+            .map(|x| &x.name)
+            .sorted()
+            .collect();
+        let is_scope_equal = board.hide_backlog_list == target_board.hide_backlog_list
+            && board.hide_closed_list == target_board.hide_closed_list
+            && source_labels == target_labels
+            && assignee_id == target_board.assignee.as_ref().map(|x| x.id)
+            && milestone_id == target_board.milestone.as_ref().map(|x| x.id)
+            && board.weight == target_board.weight;
+        if !is_scope_equal {
+            repair.updated_scope = gitlab::update_target_board_scope(
+                &target_board,
+                &board,
+                assignee_id,
+                milestone_id,
+                &membership,
+            )
+            .await?;
+        }
+
+        let mut target_lists: HashMap<_, _> = target_board
+            .lists
+            .iter()
+            .filter_map(|list| list.key().map(|key| (key, list.clone())))
+            .collect();
+        let source_lists: Vec<_> = board
+            .lists
+            .iter()
+            .filter_map(|list| list.key().map(|key| (key, list)))
+            .sorted_by_key(|(_, list)| list.position)
+            .collect();
+        for (key, list) in &source_lists {
+            if target_lists.contains_key(key) {
+                continue;
+            }
+            let field = match (&list.label, &list.assignee, &list.milestone) {
+                (Some(x), _, _) => labels.get(&x.name).map(|id| ("label_id", *id)),
+                (_, Some(x), _) => users.get(&x.username).map(|id| ("assignee_id", *id)),
+                (_, _, Some(x)) => milestones.get(&x.title).map(|id| ("milestone_id", *id)),
+                _ => None,
+            };
+            let created = match field {
+                Some(field) => {
+                    gitlab::create_target_board_list(&target_board, field, &membership).await?
+                }
+                None => None,
+            };
+            match created {
+                Some(created) => {
+                    repair.created_lists.push(key.to_owned());
+                    target_lists.insert(key.to_owned(), created);
+                }
+                None => repair.unmapped_lists.push(key.to_owned()),
+            }
+        }
+
+        // Unmapped lists leave gaps in the source positions, so lists are
+        // moved to their index among the lists that exist on the target.
+        let mapped_lists = source_lists
+            .iter()
+            .filter_map(|(key, _)| target_lists.get(key));
+        for (position, target_list) in (0..).zip(mapped_lists) {
+            if target_list.position != Some(position) {
+                gitlab::move_target_board_list(&target_board, target_list, position, &membership)
+                    .await?;
+            }
+        }
+        if repair.created_board
+            || repair.updated_scope
+            || !repair.created_lists.is_empty()
+            || !repair.unmapped_lists.is_empty()
+        {
+            repairs.push(repair);
+        }
+    }
+    Ok(repairs)
+}
+
+fn save_board_repairs(repairs: &[BoardRepair]) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/board_repairs.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &repairs)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Integrations
+// ---------------------------------------------------------------------------
+// The integrations API leaves these out of the properties altogether.
+const SECRET_PROPERTIES: [(&str, &str); 12] = [
+    ("bamboo", "password"),
+    ("datadog", "api_key"),
+    ("discord", "webhook"),
+    ("jenkins", "password"),
+    ("jira", "password"),
+    ("mattermost", "webhook"),
+    ("microsoft-teams", "webhook"),
+    ("prometheus", "google_iap_service_account_json"),
+    ("slack", "webhook"),
+    ("teamcity", "password"),
+    ("telegram", "token"),
+    ("unify-circuit", "webhook"),
+];
+
+const SECRET_PATTERNS: [&str; 6] = [
+    "password",
+    "token",
+    "secret",
+    "webhook",
+    "api_key",
+    "private_key",
+];
+
+pub async fn create_target_integrations() -> Result<(), Box<dyn Error>> {
+    let integrations = std::fs::read_to_string("cache/integrations.json")?;
+    let integrations: CachedIntegrations = serde_json::from_str(&integrations)?;
+
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let futures: Vec<_> = integrations
+        .into_iter()
+        .filter(|(_, integrations)| !integrations.is_empty())
+        .filter_map(|(key, integrations)| {
+            projects
+                .get(&key)
+                .map(|project| create_target_project_integrations(integrations, project))
+        })
+        .collect();
+    let mut secrets: Vec<_> = http::politely_try_join_all(futures, 8, 500)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+    secrets.sort_by(|x, y| (&x.project, &x.integration).cmp(&(&y.project, &y.integration)));
+    save_integration_secrets(&secrets)?;
+    Ok(())
+}
+
+async fn create_target_project_integrations(
+    integrations: Vec<SourceIntegration>,
+    project: &TargetProject,
+) -> Result<Vec<IntegrationSecrets>, Box<dyn Error>> {
+    let active: Vec<_> = gitlab::fetch_target_integrations(project)
+        .await?
+        .into_iter()
+        .filter(|integration| integration.active)
+        .map(|integration| integration.slug)
+        .collect();
+    let mut all_secrets = vec![];
+    for integration in integrations {
+        let mut secret_fields: Vec<_> = SECRET_PROPERTIES
+            .iter()
+            .filter(|(slug, _)| *slug == integration.slug)
+            .map(|(_, field)| field.to_string())
+            .collect();
+        let mut settings = HashMap::new();
+        for (field, value) in integration.properties {
+            let is_masked = value.as_str().is_some_and(|x| x.starts_with("****"));
+            if is_masked || SECRET_PATTERNS.iter().any(|x| field.contains(x)) {
+                if !secret_fields.contains(&field) {
+                    secret_fields.push(field);
+                }
+            } else if !value.is_null() {
+                settings.insert(field, value);
+            }
+        }
+        for (field, value) in integration.settings {
+            if field.ends_with("_events") {
+                settings.insert(field, value);
+            }
+        }
+        // Secrets are only validated for active integrations, so the other
+        // settings are saved inactive until the secrets are entered, unless
+        // that already happened on the target.
+        let is_active = secret_fields.is_empty() || active.contains(&integration.slug);
+        if !is_active {
+            settings.insert("active".to_string(), serde_json::Value::Bool(false));
+        }
+
+        let created =
+            gitlab::update_target_integration(&integration.slug, &settings, project).await?;
+        if !secret_fields.is_empty() || !created {
+            all_secrets.push(IntegrationSecrets {
+                project: project.key(),
+                integration: integration.title,
+                secret_fields,
+                created,
+                active: created && is_active,
+            });
+        }
+    }
+    Ok(all_secrets)
+}
+
+fn save_integration_secrets(secrets: &[IntegrationSecrets]) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/integration_secrets.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &secrets)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Runners
+// ---------------------------------------------------------------------------
+pub async fn create_target_runners() -> Result<(), Box<dyn Error>> {
+    let runners = std::fs::read_to_string("cache/runners.json")?;
+    let mut runners: CachedRunners = serde_json::from_str(&runners)?;
+
+    let groups: HashMap<_, _> = gitlab::fetch_all_target_groups()
+        .await?
+        .into_iter()
+        .map(|group| (group.key(), group))
+        .collect();
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let group_runners = runners.remove("groups").unwrap_or_default();
+    let project_runners = runners.remove("projects").unwrap_or_default();
+    let group_memberships: Vec<_> = group_runners
+        .into_iter()
+        .filter_map(|(key, runners)| {
+            groups
+                .get(&key)
+                .map(|group| (TargetMembership::Group(group.clone()), runners))
+        })
+        .collect();
+    let project_memberships: Vec<_> = project_runners
+        .into_iter()
+        .filter_map(|(key, runners)| projects.get(&key).map(|project| (project, runners)))
+        .collect();
+
+    // Runners are created one by one, because a project runner may be shared
+    // by several projects and must only be created once, in the project it
+    // was first assigned to. The other projects only enable it.
+    let mut created = load_created_runners()?;
+    for (membership, runners) in group_memberships {
+        for runner in runners {
+            if !created.contains_key(&runner.id) {
+                create_and_save_target_runner(runner, &membership, &mut created).await?;
+            }
+        }
+    }
+    for (project, runners) in &project_memberships {
+        for runner in runners {
+            let owner = runner
+                .projects
+                .iter()
+                .map(|x| &x.path_with_namespace)
+                .find(|key| projects.contains_key(*key))
+                .unwrap_or(&project.path_with_namespace);
+            if !created.contains_key(&runner.id) && *owner == project.key() {
+                let membership = TargetMembership::Project((*project).clone());
+                create_and_save_target_runner(runner.clone(), &membership, &mut created).await?;
+            }
+        }
+    }
+    for (project, runners) in &project_memberships {
+        for runner in runners {
+            match created.get(&runner.id) {
+                Some(existing) if existing.scope != project.key() => {
+                    gitlab::enable_target_project_runner(existing.id, project).await?;
+                }
+                _ => {}
+            }
+        }
+    }
+    save_runner_configs(&created)?;
+    Ok(())
+}
+
+async fn create_and_save_target_runner(
+    runner: SourceRunner,
+    membership: &TargetMembership,
+    created: &mut HashMap<u32, CreatedRunner>,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(target_runner) = gitlab::create_target_runner(&runner, membership).await? {
+        let runner = CreatedRunner {
+            source_id: runner.id,
+            id: target_runner.id,
+            description: runner.description.unwrap_or_default(),
+            runner_type: runner.runner_type,
+            scope: membership.key(),
+            token: target_runner.token,
+        };
+        created.insert(runner.source_id, runner);
+        save_created_runners(created)?;
+    }
+    Ok(())
+}
+
+fn load_created_runners() -> Result<HashMap<u32, CreatedRunner>, Box<dyn Error>> {
+    let path = "cache/created_runners.json";
+    if !std::path::Path::new(path).exists() {
+        return Ok(HashMap::new());
+    }
+    let created: Vec<CreatedRunner> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    Ok(created.into_iter().map(|x| (x.source_id, x)).collect())
+}
+
+fn save_created_runners(created: &HashMap<u32, CreatedRunner>) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/created_runners.json", dir_path);
+    let created: Vec<_> = created.values().sorted_by_key(|x| x.source_id).collect();
+    serde_json::to_writer_pretty(&create_protected_file(&json_path)?, &created)?;
+    Ok(())
+}
+
+fn save_runner_configs(created: &HashMap<u32, CreatedRunner>) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache/runner_configs";
+    std::fs::create_dir_all(dir_path)?;
+    let url = gitlab::target_web_url();
+    for runner in created.values() {
+        let toml_path = format!("{}/{}.toml", dir_path, runner.source_id);
+        let mut file = create_protected_file(&toml_path)?;
+        writeln!(
+            file,
+            "# Replaces source runner {} ({} in {}).",
+            runner.source_id, runner.runner_type, runner.scope
+        )?;
+        writeln!(
+            file,
+            "# Keep the executor settings of the existing [[runners]] entry."
+        )?;
+        writeln!(file, "[[runners]]")?;
+        writeln!(file, "  name = {:?}", runner.description)?;
+        writeln!(file, "  url = {:?}", url)?;
+        writeln!(file, "  token = {:?}", runner.token)?;
+    }
+    println!("Successfully wrote to {}!", dir_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Reassign Target Issues
+// ---------------------------------------------------------------------------
+pub async fn reassign_target_issues() -> Result<(), Box<dyn Error>> {
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .filter(|project| !project.archived)
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+
+    let all_issues = std::fs::read_to_string("cache/issues.json")?;
+    let all_issues: CachedIssues = serde_json::from_str(&all_issues)?;
+
+    let futures: Vec<_> = all_issues
+        .into_iter()
+        .flat_map(|(key, issues)| match projects.get(&key) {
+            Some(project) => {
+                let pairs: Vec<_> = issues
+                    .into_iter()
+                    .filter_map(|issue| {
+                        let assignees: Vec<_> = issue
+                            .all_assignees()
+                            .iter()
+                            .filter_map(|assignee| users.get(&assignee.username))
+                            .cloned()
+                            .collect();
+                        if assignees.is_empty() {
+                            None
+                        } else {
+                            Some(gitlab::reassign_target_issue(issue, project, assignees))
+                        }
+                    })
+                    .collect();
+                pairs
+            }
+            None => vec![],
+        })
+        .collect();
+    http::politely_try_join_all(futures, 24, 500).await?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Reassign Target Merge Requests
+// ---------------------------------------------------------------------------
+pub async fn reassign_target_merge_requests() -> Result<(), Box<dyn Error>> {
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .filter(|project| !project.archived)
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+
+    let all_merge_requests = std::fs::read_to_string("cache/merge_requests.json")?;
+    let all_merge_requests: CachedMergeRequests = serde_json::from_str(&all_merge_requests)?;
+
+    let futures: Vec<_> = all_merge_requests
+        .into_iter()
+        .flat_map(|(key, merge_requests)| match projects.get(&key) {
+            Some(project) => {
+                let pairs: Vec<_> = merge_requests
+                    .into_iter()
+                    .filter_map(|merge_request| {
+                        let assignees: Vec<_> = merge_request
+                            .assignees
+                            .iter()
+                            .filter_map(|assignee| users.get(&assignee.username))
+                            .cloned()
+                            .collect();
+                        let reviewers: Vec<_> = merge_request
+                            .reviewers
+                            .iter()
+                            .filter_map(|reviewer| users.get(&reviewer.username))
+                            .cloned()
+                            .collect();
+                        if assignees.is_empty() && reviewers.is_empty() {
+                            None
+                        } else {
+                            Some(gitlab::reassign_target_merge_request(
+                                merge_request,
+                                project,
+                                assignees,
+                                reviewers,
+                            ))
+                        }
+                    })
+                    .collect();
+                pairs
+            }
+            None => vec![],
+        })
+        .collect();
+    http::politely_try_join_all(futures, 24, 500).await?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Restore Target Time Tracking
+// ---------------------------------------------------------------------------
+pub async fn restore_target_time_tracking() -> Result<(), Box<dyn Error>> {
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+    let importer = gitlab::fetch_target_token_username().await?;
+
+    let all_issues = std::fs::read_to_string("cache/issues.json")?;
+    let all_issues: CachedIssues = serde_json::from_str(&all_issues)?;
+    let mut tracked: Vec<_> = all_issues
+        .into_iter()
+        .flat_map(|(key, issues)| {
+            issues.into_iter().map(move |issue| {
+                let time = (issue.time_stats.time_estimate, issue.timelogs);
+                (key.to_owned(), Issuable::Issue, issue.iid, time)
+            })
+        })
+        .collect();
+    if std::path::Path::new("cache/merge_requests.json").exists() {
+        let all_merge_requests = std::fs::read_to_string("cache/merge_requests.json")?;
+        let all_merge_requests: CachedMergeRequests = serde_json::from_str(&all_merge_requests)?;
+        tracked.extend(
+            all_merge_requests
+                .into_iter()
+                .flat_map(|(key, merge_requests)| {
+                    merge_requests.into_iter().map(move |merge_request| {
+                        let time = (
+                            merge_request.time_stats.time_estimate,
+                            merge_request.timelogs,
+                        );
+                        (
+                            key.to_owned(),
+                            Issuable::MergeRequest,
+                            merge_request.iid,
+                            time,
+                        )
+                    })
+                }),
+        );
+    }
+
+    // Timelogs are restored one issuable at a time, so that the impersonation
+    // tokens can be reused across projects and revoked once at the end.
+    let mut tokens = HashMap::new();
+    let mut unrestored = vec![];
+    let mut result = Ok(());
+    for (key, issuable, iid, time) in tracked {
+        if time.0 == 0 && time.1.is_empty() {
+            continue;
+        }
+        let project = match projects.get(&key) {
+            Some(project) => project,
+            None => continue,
+        };
+        let restored = restore_target_issuable_time_tracking(
+            project,
+            issuable,
+            iid,
+            time,
+            &users,
+            &importer,
+            &mut tokens,
+        )
+        .await;
+        match restored {
+            Ok(mut x) => unrestored.append(&mut x),
+            Err(err) => {
+                result = Err(err);
+                break;
+            }
+        }
+    }
+    for (username, token) in tokens {
+        if let Some(user) = users.get(&username) {
+            gitlab::revoke_target_impersonation_token(user, &token).await?;
+        }
+    }
+    result?;
+    save_unrestored_timelogs(&unrestored)?;
+    Ok(())
+}
+
+async fn restore_target_issuable_time_tracking(
+    project: &TargetProject,
+    issuable: Issuable,
+    iid: u32,
+    time: (i64, Vec<Timelog>),
+    users: &HashMap<String, TargetUser>,
+    importer: &str,
+    tokens: &mut HashMap<String, TargetAccessToken>,
+) -> Result<Vec<UnrestoredTimelog>, Box<dyn Error>> {
+    let (time_estimate, timelogs) = time;
+    let mut unrestored = vec![];
+    let target_issuable = match gitlab::fetch_target_issuable(project, issuable, iid).await? {
+        Some(target_issuable) => target_issuable,
+        None => return Ok(unrestored),
+    };
+    if target_issuable.time_stats.time_estimate != time_estimate {
+        gitlab::set_target_time_estimate(project, issuable, iid, time_estimate).await?;
+    }
+
+    let mut existing = gitlab::fetch_target_timelogs(project, issuable, iid).await?;
+    let name = format!("{}#{}", issuable.url_prefix(), iid);
+    for timelog in timelogs {
+        let is_same_entry = |x: &GraphqlTimelog| {
+            x.time_spent == timelog.time_spent && x.spent_at == timelog.spent_at
+        };
+        let matching = existing
+            .iter()
+            .position(|x| is_same_entry(x) && x.user.username == timelog.username);
+        if let Some(index) = matching {
+            existing.remove(index);
+            continue;
+        }
+        let user = match users.get(&timelog.username) {
+            Some(user) => user,
+            None => {
+                unrestored.push(UnrestoredTimelog {
+                    project: project.key(),
+                    issuable: name.to_owned(),
+                    timelog,
+                    reason: "User does not exist on the target".to_string(),
+                });
+                continue;
+            }
+        };
+        let token = match tokens.entry(user.key()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match gitlab::create_target_impersonation_token(user).await {
+                Ok(token) => entry.insert(token),
+                Err(err) => {
+                    unrestored.push(UnrestoredTimelog {
+                        project: project.key(),
+                        issuable: name.to_owned(),
+                        timelog,
+                        reason: err.to_string(),
+                    });
+                    continue;
+                }
+            },
+        };
+
+        println!(
+            "Adding {}s spent by {} to {} in {}...",
+            timelog.time_spent,
+            user.key(),
+            name,
+            project.key()
+        );
+        let private_token = token.token.to_owned().unwrap_or_default();
+        let created =
+            gitlab::create_target_timelog(&timelog, issuable, &target_issuable, &private_token)
+                .await;
+        match created {
+            Ok(()) => {
+                // The import attributes time spent by unmapped users to the
+                // importer, which would otherwise be counted twice.
+                let attributed = existing
+                    .iter()
+                    .position(|x| is_same_entry(x) && x.user.username == importer);
+                if let Some(index) = attributed {
+                    gitlab::delete_target_timelog(&existing.remove(index)).await?;
+                }
+            }
+            Err(err) => unrestored.push(UnrestoredTimelog {
+                project: project.key(),
+                issuable: name.to_owned(),
+                timelog,
+                reason: err.to_string(),
+            }),
+        }
+    }
+    Ok(unrestored)
+}
+
+fn save_unrestored_timelogs(unrestored: &[UnrestoredTimelog]) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/unrestored_timelogs.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &unrestored)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Delete Target Pipeline Schedules
+// ---------------------------------------------------------------------------
+pub async fn delete_target_pipeline_schedules() -> Result<(), Box<dyn Error>> {
+    let projects: Vec<_> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .collect();
+
+    let futures: Vec<_> = projects
+        .iter()
+        .map(gitlab::delete_target_pipeline_schedules)
+        .collect();
+    http::politely_try_join_all(futures, 24, 500).await?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Pipeline Schedules
+// ---------------------------------------------------------------------------
+pub async fn create_target_pipeline_schedules() -> Result<(), Box<dyn Error>> {
+    let projects: HashMap<_, _> = gitlab::fetch_all_target_projects()
+        .await?
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+    let fallback_owner = env::load_env("PIPELINE_SCHEDULE_FALLBACK_OWNER");
+    let fallback_owner = users.get(&fallback_owner);
+
+    let all_schedules = std::fs::read_to_string("cache/pipeline_schedules.json")?;
+    let all_schedules: CachedPipelineSchedules = serde_json::from_str(&all_schedules)?;
+
+    let futures: Vec<_> = all_schedules
+        .into_iter()
+        .filter_map(|(key, schedules)| {
+            projects.get(&key).map(|project| {
+                create_target_project_pipeline_schedules(schedules, project, &users, fallback_owner)
+            })
+        })
+        .collect();
+    let skipped: Vec<_> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if !skipped.is_empty() {
+        println!(
+            "Skipped the following pipeline schedules without an owner to impersonate:\n{:#?}",
+            skipped
+        );
+    }
+    Ok(())
+}
+
+async fn create_target_project_pipeline_schedules(
+    schedules: Vec<SourcePipelineSchedule>,
+    project: &TargetProject,
+    users: &HashMap<String, TargetUser>,
+    fallback_owner: Option<&TargetUser>,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let existing = gitlab::fetch_all_target_pipeline_schedules(project).await?;
+    let mut skipped = vec![];
+    for schedule in schedules {
+        let owner = schedule
+            .owner
+            .as_ref()
+            .and_then(|owner| users.get(&owner.username));
+        let (owner, token) = match impersonate_owner(owner, fallback_owner).await {
+            Some(x) => x,
+            None => {
+                skipped.push(format!("{} in {}", schedule.description, project.key()));
+                continue;
+            }
+        };
+        let private_token = token.token.to_owned().unwrap_or_default();
+
+        let matching = existing
+            .iter()
+            .find(|x| x.description == schedule.description);
+        let result = match matching {
+            Some(matching) => {
+                gitlab::take_ownership_of_target_pipeline_schedule(
+                    schedule,
+                    matching,
+                    project,
+                    &private_token,
+                )
+                .await
+            }
+            None => {
+                gitlab::create_target_pipeline_schedule(schedule, project, &private_token).await
+            }
+        };
+        gitlab::revoke_target_impersonation_token(owner, &token).await?;
+        result?;
+    }
+    Ok(skipped)
+}
+
+// Falls back to the configured owner when the original owner is missing or
+// cannot be impersonated, e.g. because they are blocked.
+async fn impersonate_owner<'a>(
+    owner: Option<&'a TargetUser>,
+    fallback_owner: Option<&'a TargetUser>,
+) -> Option<(&'a TargetUser, TargetAccessToken)> {
+    for user in owner.into_iter().chain(fallback_owner) {
+        if let Some(token) = impersonate_target_user(user).await {
+            return Some((user, token));
+        }
+    }
+    None
+}
+
+// ---------------------------------------------------------------------------
+// Add Target Users to Projects
+// ---------------------------------------------------------------------------
+pub async fn add_target_users_to_projects() -> Result<(), Box<dyn Error>> {
+    let projects = gitlab::fetch_all_target_projects().await?;
+    let project_ids: HashMap<_, _> = projects
+        .into_iter()
+        .map(|project| (project.key(), project))
+        .collect();
+
+    let user_ids = fetch_target_users_by_source_username().await?;
+
+    let memberships = std::fs::read_to_string("cache/memberships.json")?;
+    let mut memberships: CachedMemberships = serde_json::from_str(&memberships)?;
+    let project_memberships = memberships.remove("projects").unwrap_or_default();
+
+    let futures: Vec<_> = project_memberships
+        .into_iter()
+        .flat_map(|(project_key, members)| {
+            members
+                .into_iter()
+                .map(move |member| (project_key.clone(), member))
+        })
+        .filter_map(|(project_key, member)| {
+            let project_option = project_ids.get(&project_key);
+            let user_option = user_ids.get(&member.username);
+            match (project_option, user_option) {
+                (Some(project), Some(user)) => Some(gitlab::add_target_project_member_to_project(
+                    project.clone(),
+                    user.clone(),
+                    member,
+                )),
+                _ => None,
+            }
+        })
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Add Target Users to Groups
+// ---------------------------------------------------------------------------
+pub async fn add_target_users_to_groups() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_target_groups().await?;
+    let group_ids: HashMap<_, _> = groups
+        .into_iter()
+        .map(|group| (group.key(), group))
+        .collect();
+
+    let user_ids = fetch_target_users_by_source_username().await?;
+
+    let memberships = std::fs::read_to_string("cache/memberships.json")?;
+    let mut memberships: CachedMemberships = serde_json::from_str(&memberships)?;
+    let group_memberships = memberships.remove("groups").unwrap_or_default();
+
+    let futures: Vec<_> = group_memberships
+        .into_iter()
+        .flat_map(|(group_path, members)| {
+            members
+                .into_iter()
+                .map(move |member| (group_path.clone(), member))
+        })
+        .filter_map(|(group_path, member)| {
+            let group_option = group_ids.get(&group_path);
+            let user_option = user_ids.get(&member.username);
+            match (group_option, user_option) {
+                (Some(group), Some(user)) => Some(gitlab::add_target_project_member_to_group(
+                    group.clone(),
+                    user.clone(),
+                    member,
+                )),
+                _ => None,
+            }
+        })
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Delete Target Projects
+// ---------------------------------------------------------------------------
+pub async fn delete_target_projects() -> Result<(), Box<dyn Error>> {
+    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
+    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
+    let project_paths: Vec<_> = metadata
+        .values()
+        .map(|project| project.path_with_namespace.to_string())
+        .collect();
+
+    let all_projects = gitlab::fetch_all_target_projects().await?;
+    let futures: Vec<_> = all_projects
+        .into_iter()
+        .filter(|project| project_paths.contains(&project.path_with_namespace))
+        .map(gitlab::delete_target_project)
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Verify Target User Emails
+// ---------------------------------------------------------------------------
+pub async fn verify_target_user_emails() -> Result<(), Box<dyn Error>> {
+    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
+    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
+
+    let target_emails: Vec<_> = gitlab::fetch_all_target_users()
+        .await?
+        .into_iter()
+        .map(|user| user.email.to_lowercase())
+        .collect();
+
+    let source_usernames: HashMap<_, _> = load_users_to_create()?
+        .into_iter()
+        .map(|user| (user.id, user.username))
+        .collect();
+
+    let mut unmapped = vec![];
+    for project in metadata.into_values() {
+        let gz_path = format!("cache/projects/{}.gz", project.id);
+        if !std::path::Path::new(&gz_path).exists() {
+            println!("Skipping {} without an export archive...", project.key());
+            continue;
+        }
+        println!("Checking contributors of {}...", project.key());
+        let contributors = archive::read_export_contributors(&gz_path)?;
+        let mut member_ids = vec![];
+        // Pending invites are exported without a user.
+        for user in contributors.members.into_iter().filter_map(|x| x.user) {
+            member_ids.push(user.id);
+            let is_mapped = user
+                .public_email
+                .as_ref()
+                .map(|email| target_emails.contains(&email.to_lowercase()))
+                .unwrap_or(false);
+            if !is_mapped {
+                let reason = match user.public_email {
+                    Some(_) => "no target user has the member's public email",
+                    None => "member has no public email in the export",
+                };
+                unmapped.push(UnmappedContributor {
+                    project: project.key(),
+                    source_user_id: user.id,
+                    username: Some(user.username),
+                    public_email: user.public_email,
+                    reason: reason.to_string(),
+                });
+            }
+        }
+        for author_id in contributors.author_ids.into_iter().sorted() {
+            if !member_ids.contains(&author_id) {
+                unmapped.push(UnmappedContributor {
+                    project: project.key(),
+                    source_user_id: author_id,
+                    username: source_usernames.get(&author_id).cloned(),
+                    public_email: None,
+                    reason: "author is not a project member in the export".to_string(),
+                });
+            }
+        }
+    }
+
+    println!(
+        "Found {} contributors that will be attributed to the importing user!",
+        unmapped.len()
+    );
+    save_unmapped_contributors(&unmapped)?;
+    Ok(())
+}
+
+fn save_unmapped_contributors(unmapped: &[UnmappedContributor]) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/unmapped_contributors.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &unmapped)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Import Target Projects
+// ---------------------------------------------------------------------------
+pub async fn import_target_projects() -> Result<(), Box<dyn Error>> {
+    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
+    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
+
+    let existing_projects = gitlab::fetch_all_target_projects().await?;
+    let existing_paths: Vec<_> = existing_projects
+        .into_iter()
+        .map(|project| project.path_with_namespace)
+        .collect();
+
+    let remaining_projects: Vec<_> = metadata
+        .into_values()
+        .filter(|project| !existing_paths.contains(&project.path_with_namespace))
+        .collect();
+    let num_remaining = remaining_projects.len();
+    for (index, project) in remaining_projects.into_iter().enumerate() {
+        let _ = gitlab::import_target_project(project).await;
+        println!("Num. remaining projects: {}", num_remaining - index - 1);
+        http::throttle_for_ms(10 * 1000);
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Delete Target Users
+// ---------------------------------------------------------------------------
+pub async fn delete_target_users() -> Result<(), Box<dyn Error>> {
+    let memberships = std::fs::read_to_string("cache/memberships.json")?;
+    let memberships: CachedMemberships = serde_json::from_str(&memberships)?;
+    let usernames: Vec<_> = memberships
+        .values()
+        .flat_map(|user| user.values())
+        .flatten()
+        .unique_by(|user| user.id)
+        .map(|user| user.username.to_string())
+        .collect();
+
+    let all_target_users = gitlab::fetch_all_target_users().await?;
+    let futures: Vec<_> = all_target_users
+        .into_iter()
+        .filter(|user| usernames.contains(&user.username))
+        .map(gitlab::delete_target_user)
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Create Target User Keys
+// ---------------------------------------------------------------------------
+pub async fn create_target_user_keys() -> Result<(), Box<dyn Error>> {
+    let user_keys = std::fs::read_to_string("cache/user_keys.json")?;
+    let user_keys: CachedUserKeys = serde_json::from_str(&user_keys)?;
+
+    let users: HashMap<_, _> = fetch_target_users_by_source_username().await?;
+
+    let mut futures = vec![];
+    for (username, keys) in user_keys {
+        match users.get(&username) {
+            Some(user) => futures.push(create_target_keys_of_user(user, keys)),
+            None => println!("Skipping keys of missing user {}...", username),
+        }
+    }
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+async fn create_target_keys_of_user(
+    user: &TargetUser,
+    keys: SourceUserKeys,
+) -> Result<(), Box<dyn Error>> {
+    if !keys.ssh_keys.is_empty() {
+        // Keys are compared without their comments, which may differ.
+        let existing: Vec<_> = gitlab::fetch_target_ssh_keys(user)
+            .await?
+            .into_iter()
+            .map(|key| ssh_key_without_comment(&key.key))
+            .collect();
+        for key in &keys.ssh_keys {
+            if !existing.contains(&ssh_key_without_comment(&key.key)) {
+                gitlab::create_target_ssh_key(key, user).await?;
+            }
+        }
+    }
+    if !keys.gpg_keys.is_empty() {
+        let existing: Vec<_> = gitlab::fetch_target_gpg_keys(user)
+            .await?
+            .into_iter()
+            .map(|key| key.key.trim().to_string())
+            .collect();
+        for key in &keys.gpg_keys {
+            if !existing.contains(&key.key.trim().to_string()) {
+                gitlab::create_target_gpg_key(key, user).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn ssh_key_without_comment(key: &str) -> String {
+    key.split_whitespace().take(2).join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// Create Target Users
+// ---------------------------------------------------------------------------
+pub async fn create_target_users() -> Result<(), Box<dyn Error>> {
+    let email_mapping = std::fs::read_to_string("cache/username_email_mapping.json")?;
+    let email_mapping: HashMap<String, String> = serde_json::from_str(&email_mapping)?;
+    println!(
+        "Using the following username-to-email mapping:\n{:#?}",
+        email_mapping
+    );
+
+    let identities = load_identity_mapping(&email_mapping)?;
+    let mut profiles = load_source_users()?;
+    let users_to_create = load_users_to_create()?;
+    let source_ids: HashMap<_, _> = users_to_create
+        .iter()
+        .map(|user| (user.username.to_owned(), user.id))
+        .collect();
+    let existing_users =
+        map_target_users_by_source_username(gitlab::fetch_all_target_users().await?, &source_ids);
+    let futures: Vec<_> = users_to_create
+        .iter()
+        .filter_map(|user| {
+            let existing = existing_users.get(&user.username)?;
+            let identity = identities.get(&user.username)?;
+            let is_linked = existing.identities.contains(identity);
+            (!is_linked).then(|| gitlab::update_target_user_identity(existing, identity))
+        })
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+
+    let futures: Vec<_> = users_to_create
+        .iter()
+        .filter_map(|user| {
+            let existing = existing_users.get(&user.username)?;
+            let profile = profiles.get(&user.username)?;
+            (existing.external != profile.external)
+                .then(|| gitlab::update_target_user_external(existing, profile.external))
+        })
+        .collect();
+    http::politely_try_join_all(futures, 8, 500).await?;
+
+    let futures: Vec<_> = users_to_create
+        .into_iter()
+        .filter(|user| !existing_users.contains_key(&user.username))
+        .map(|user| {
+            let profile = profiles.remove(&user.username);
+            let identity = identities.get(&user.username).cloned();
+            gitlab::create_target_user(user, &email_mapping, profile, identity)
+        })
+        .collect();
+    println!("Creating target users for {} users...", futures.len());
+    let created_users = http::politely_try_join_all(futures, 8, 500).await?;
+
+    let futures: Vec<_> = existing_users
+        .values()
+        .chain(created_users.iter())
+        .filter(|user| user.custom_attribute(MIGRATED_FROM_ID).is_none())
+        .filter_map(|user| {
+            let source_id = source_ids.get(&user.username)?;
+            Some(record_source_user(user, *source_id))
+        })
+        .collect();
+    println!("Recording source accounts for {} users...", futures.len());
+    http::politely_try_join_all(futures, 8, 500).await?;
+    Ok(())
+}
+
+const MIGRATED_FROM_ID: &str = "migrated_from_id";
+const MIGRATED_FROM_HOST: &str = "migrated_from_host";
+
+async fn record_source_user(user: &TargetUser, source_id: u32) -> Result<(), Box<dyn Error>> {
+    let source_id = source_id.to_string();
+    gitlab::set_target_user_custom_attribute(user, MIGRATED_FROM_ID, &source_id).await?;
+    gitlab::set_target_user_custom_attribute(user, MIGRATED_FROM_HOST, &gitlab::source_host()).await
+}
+
+fn load_identity_mapping(
+    email_mapping: &HashMap<String, String>,
+) -> Result<HashMap<String, UserIdentity>, Box<dyn Error>> {
+    let json_path = "cache/identity_mapping.json";
+    let mut identities: HashMap<String, UserIdentity> = if std::path::Path::new(json_path).exists()
+    {
+        serde_json::from_str(&std::fs::read_to_string(json_path)?)?
+    } else {
+        HashMap::new()
+    };
+    // Users missing from the file are linked to the default provider with
+    // their mapped email as the external UID.
+    let provider = env::load_env("IDENTITY_PROVIDER");
+    if !provider.is_empty() {
+        for (username, email) in email_mapping {
+            identities
+                .entry(username.to_owned())
+                .or_insert_with(|| UserIdentity {
+                    provider: provider.to_owned(),
+                    extern_uid: email.to_owned(),
+                });
+        }
+    }
+    Ok(identities)
+}
+
+fn load_source_users() -> Result<CachedUsers, Box<dyn Error>> {
+    let json_path = "cache/users.json";
+    if !std::path::Path::new(json_path).exists() {
+        return Ok(HashMap::new());
+    }
+    let users = std::fs::read_to_string(json_path)?;
+    Ok(serde_json::from_str(&users)?)
+}
+
+pub fn load_users_to_create() -> Result<Vec<SourceUser>, Box<dyn Error>> {
+    let profiles = load_source_users()?;
+    let bot_mapping = load_bot_user_mapping()?;
+    let memberships = std::fs::read_to_string("cache/memberships.json")?;
+    let memberships: CachedMemberships = serde_json::from_str(&memberships)?;
+    let users_from_memberships = memberships
+        .values()
+        .flat_map(|member| member.values())
+        .flatten()
+        .map(|user| user.to_user());
+
+    let issues = std::fs::read_to_string("cache/issues.json")?;
+    let issues: CachedIssues = serde_json::from_str(&issues)?;
+    let users_from_issues = issues.values().flat_map(|project_issues| {
+        let users: Vec<_> = project_issues
+            .iter()
+            .flat_map(|issue| {
+                let mut users = issue.all_assignees();
+                users.push(issue.author.clone());
+                users
+            })
+            .collect();
+        users
+    });
+
+    let users_from_merge_requests = load_users_from_merge_requests()?;
+
+    let users_to_create = users_from_memberships
+        .chain(users_from_issues)
+        .chain(users_from_merge_requests)
+        .unique_by(|user| user.id)
+        .filter(|user| {
+            let is_bot = user.is_internal_bot()
+                || profiles.get(&user.username).is_some_and(|x| x.bot)
+                || bot_mapping.contains_key(&user.username);
+            if is_bot {
+                println!("Skipping bot user {}...", user.username);
+            }
+            !is_bot
+        })
+        .collect();
+    Ok(users_to_create)
+}
+
+fn load_bot_user_mapping() -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let json_path = "cache/bot_user_mapping.json";
+    if !std::path::Path::new(json_path).exists() {
+        return Ok(HashMap::new());
+    }
+    let mapping = std::fs::read_to_string(json_path)?;
+    Ok(serde_json::from_str(&mapping)?)
+}
+
+pub async fn fetch_target_users_by_source_username(
+) -> Result<HashMap<String, TargetUser>, Box<dyn Error>> {
+    let target_users = gitlab::fetch_all_target_users().await?;
+    let mut source_ids: HashMap<_, _> = load_source_users()?
+        .into_iter()
+        .map(|(username, profile)| (username, profile.id))
+        .collect();
+    source_ids.extend(
+        load_users_to_create()?
+            .into_iter()
+            .map(|user| (user.username, user.id)),
+    );
+    let mut users = map_target_users_by_source_username(target_users, &source_ids);
+    // Bots resolve to their service accounts, so that their issues,
+    // memberships and approvals are kept.
+    for (bot_username, username) in load_bot_user_mapping()? {
+        match users.get(&username) {
+            Some(user) => {
+                let user = user.clone();
+                users.insert(bot_username, user);
+            }
+            None => println!(
+                "Service account {} for bot user {} does not exist on the target!",
+                username, bot_username
+            ),
+        }
+    }
+    Ok(users)
+}
+
+fn map_target_users_by_source_username(
+    target_users: Vec<TargetUser>,
+    source_ids: &HashMap<String, u32>,
+) -> HashMap<String, TargetUser> {
+    let source_host = gitlab::source_host();
+    let users_by_source_id: HashMap<u32, TargetUser> = target_users
+        .iter()
+        .filter(|user| user.custom_attribute(MIGRATED_FROM_HOST) == Some(source_host.as_str()))
+        .filter_map(|user| {
+            let source_id = user.custom_attribute(MIGRATED_FROM_ID)?.parse().ok()?;
+            Some((source_id, user.clone()))
+        })
+        .collect();
+    let mut users: HashMap<_, _> = target_users
+        .into_iter()
+        .map(|user| (user.key(), user))
+        .collect();
+    // Renamed target users are still found by the source ID they recorded.
+    for (username, source_id) in source_ids {
+        if let Some(user) = users_by_source_id.get(source_id) {
+            users.insert(username.to_owned(), user.clone());
+        }
+    }
+    users
+}
+
+fn load_users_from_merge_requests() -> Result<Vec<SourceUser>, Box<dyn Error>> {
+    let json_path = "cache/merge_requests.json";
+    if !std::path::Path::new(json_path).exists() {
+        return Ok(vec![]);
+    }
+    let merge_requests = std::fs::read_to_string(json_path)?;
+    let merge_requests: CachedMergeRequests = serde_json::from_str(&merge_requests)?;
+    let users = merge_requests
+        .into_values()
+        .flatten()
+        .flat_map(|merge_request| {
+            let mut users = merge_request.assignees;
+            users.extend(merge_request.reviewers);
+            users.push(merge_request.author);
+            users
+        })
+        .collect();
+    Ok(users)
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Users
+// ---------------------------------------------------------------------------
+pub async fn download_source_users() -> Result<(), Box<dyn Error>> {
+    let users = load_users_to_create()?;
+    let futures: Vec<_> = users
+        .iter()
+        .map(gitlab::fetch_source_user_profile)
+        .collect();
+    let profiles: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .flatten()
+        .map(|profile| (profile.username.to_owned(), profile))
+        .collect();
+    save_source_users(&profiles)?;
+    Ok(())
+}
+
+fn save_source_users(users: &CachedUsers) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/users.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &users)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Download Source User Keys
+// ---------------------------------------------------------------------------
+pub async fn download_source_user_keys() -> Result<(), Box<dyn Error>> {
+    let users = load_users_to_create()?;
+    let futures: Vec<_> = users.iter().map(fetch_source_user_keys).collect();
+    let user_keys: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .filter(|(_, keys)| !keys.ssh_keys.is_empty() || !keys.gpg_keys.is_empty())
+        .collect();
+    save_source_user_keys(&user_keys)?;
+    Ok(())
+}
+
+fn save_source_user_keys(user_keys: &CachedUserKeys) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/user_keys.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &user_keys)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_source_user_keys(
+    user: &SourceUser,
+) -> Result<(String, SourceUserKeys), Box<dyn Error>> {
+    let keys = gitlab::fetch_source_user_keys(user).await?;
+    Ok((user.username.to_owned(), keys))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Access Tokens
+// ---------------------------------------------------------------------------
+pub async fn download_source_access_tokens() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups
+        .iter()
+        .map(|group| fetch_source_access_tokens(Membership::Group(group.clone())))
+        .collect();
+    let group_tokens: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+
+    let projects = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects
+        .into_iter()
+        .map(|project| fetch_source_access_tokens(Membership::Project(project)))
+        .collect();
+    let project_tokens: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+
+    let all_tokens = HashMap::from([
+        ("groups".to_string(), group_tokens),
+        ("projects".to_string(), project_tokens),
+    ]);
+    save_source_access_tokens(&all_tokens)?;
+    Ok(())
+}
+
+fn save_source_access_tokens(access_tokens: &CachedAccessTokens) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/access_tokens.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &access_tokens)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_source_access_tokens(
+    membership: Membership,
+) -> Result<(String, Vec<SourceAccessToken>), Box<dyn Error>> {
+    let key = membership.key();
+    let tokens = gitlab::fetch_all_source_access_tokens(membership).await?;
+    Ok((key, tokens))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Group Labels
+// ---------------------------------------------------------------------------
+pub async fn download_source_group_labels() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups.iter().map(fetch_all_source_group_labels).collect();
+    let labels: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+    save_source_group_labels(&labels)?;
+    Ok(())
+}
+
+fn save_source_group_labels(labels: &CachedGroupLabels) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/group_labels.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &labels)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_all_source_group_labels(
+    group: &SourceGroup,
+) -> Result<(String, Vec<SourceLabel>), Box<dyn Error>> {
+    let key = group.key();
+    let labels = gitlab::fetch_all_source_group_labels(group).await?;
+    Ok((key, labels))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Group Milestones
+// ---------------------------------------------------------------------------
+pub async fn download_source_group_milestones() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups
+        .iter()
+        .map(fetch_all_source_group_milestones)
+        .collect();
+    let milestones: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+    save_source_group_milestones(&milestones)?;
+    Ok(())
+}
+
+fn save_source_group_milestones(milestones: &CachedGroupMilestones) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/group_milestones.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &milestones)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_all_source_group_milestones(
+    group: &SourceGroup,
+) -> Result<(String, Vec<SourceMilestone>), Box<dyn Error>> {
+    let key = group.key();
+    let milestones = gitlab::fetch_all_source_group_milestones(group).await?;
+    Ok((key, milestones))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Epics
+// ---------------------------------------------------------------------------
+pub async fn download_source_epics() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups.iter().map(fetch_all_source_epics).collect();
+    let epics: HashMap<_, _> = http::politely_try_join_all(futures, 8, 500)
+        .await?
+        .into_iter()
+        .collect();
+    save_source_epics(&epics)?;
+    Ok(())
+}
+
+fn save_source_epics(epics: &CachedEpics) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/epics.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &epics)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_all_source_epics(
+    group: &SourceGroup,
+) -> Result<(String, Vec<SourceEpic>), Box<dyn Error>> {
+    let key = group.key();
+    let epics = gitlab::fetch_all_source_epics(group).await?;
+    Ok((key, epics))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Badges
+// ---------------------------------------------------------------------------
+pub async fn download_source_badges() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups
+        .iter()
+        .map(|group| fetch_source_badges(Membership::Group(group.clone())))
+        .collect();
+    let group_badges: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+
+    let projects = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects
+        .into_iter()
+        .map(|project| fetch_source_badges(Membership::Project(project)))
+        .collect();
+    let project_badges: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+
+    let all_badges = HashMap::from([
+        ("groups".to_string(), group_badges),
+        ("projects".to_string(), project_badges),
+    ]);
+    save_source_badges(&all_badges)?;
+    Ok(())
+}
+
+fn save_source_badges(badges: &CachedBadges) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/badges.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &badges)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_source_badges(
+    membership: Membership,
+) -> Result<(String, Vec<SourceBadge>), Box<dyn Error>> {
+    let key = membership.key();
+    let badges = gitlab::fetch_all_source_badges(membership).await?;
+    Ok((key, badges))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Snippets
+// ---------------------------------------------------------------------------
+pub async fn download_source_snippets() -> Result<(), Box<dyn Error>> {
+    if !gitlab::fetch_source_token_is_admin().await? {
+        return Err("Downloading snippets requires an administrator token on the source!".into());
+    }
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let projects = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects.iter().map(fetch_source_project_snippets).collect();
+    let project_snippets: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+
+    let usernames: HashSet<_> = load_users_to_create()?
+        .into_iter()
+        .map(|user| user.username)
+        .collect();
+    let personal_snippets = gitlab::fetch_all_source_personal_snippets(&usernames)
+        .await?
+        .into_iter()
+        .map(|snippet| (snippet.author.username.to_owned(), snippet))
+        .into_group_map();
+
+    let all_snippets = HashMap::from([
+        ("projects".to_string(), project_snippets),
+        ("users".to_string(), personal_snippets),
+    ]);
+    save_source_snippets(&all_snippets)?;
+    Ok(())
+}
+
+fn save_source_snippets(snippets: &CachedSnippets) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/snippets.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &snippets)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_source_project_snippets(
+    project: &SourceProject,
+) -> Result<(String, Vec<SourceSnippet>), Box<dyn Error>> {
+    let snippets = gitlab::fetch_source_project_snippets(project).await?;
+    Ok((project.key(), snippets))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Approval Rules
+// ---------------------------------------------------------------------------
+pub async fn download_source_approval_rules() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let projects: Vec<_> = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects.iter().map(fetch_source_approvals).collect();
+    let approvals: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+    save_source_approvals(&approvals)?;
+    Ok(())
+}
+
+fn save_source_approvals(approvals: &CachedApprovals) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/approvals.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &approvals)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_source_approvals(
+    project: &SourceProject,
+) -> Result<(String, SourceProjectApprovals), Box<dyn Error>> {
+    let key = project.key();
+    let approvals = gitlab::fetch_source_approvals(project).await?;
+    Ok((key, approvals))
+}
+
+// ---------------------------------------------------------------------------
+// Download Source Environments
 // ---------------------------------------------------------------------------
-pub async fn add_target_users_to_groups() -> Result<(), Box<dyn Error>> {
-    let groups = gitlab::fetch_all_target_groups().await?;
-    let group_ids: HashMap<_, _> = groups
-        .into_iter()
-        .map(|group| (group.key(), group))
-        .collect();
-
-    let users = gitlab::fetch_all_target_users().await?;
-    let user_ids: HashMap<_, _> = users
+pub async fn download_source_environments() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let group_paths: HashMap<_, _> = groups.iter().map(|group| (group.id, group.key())).collect();
+    let projects: Vec<_> = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects.iter().map(fetch_source_environments).collect();
+    let mut environments: CachedEnvironments = http::politely_try_join_all(futures, 24, 500)
+        .await?
         .into_iter()
-        .map(|user| (user.username.clone(), user))
         .collect();
 
-    let memberships = std::fs::read_to_string("cache/memberships.json")?;
-    let mut memberships: CachedMemberships = serde_json::from_str(&memberships)?;
-    let group_memberships = memberships.remove("groups").unwrap_or_default();
-
-    let futures: Vec<_> = group_memberships
-        .into_iter()
-        .flat_map(|(group_path, members)| {
-            members
-                .into_iter()
-                .map(move |member| (group_path.clone(), member))
-        })
-        .filter_map(|(group_path, member)| {
-            let group_option = group_ids.get(&group_path);
-            let user_option = user_ids.get(&member.username);
-            match (group_option, user_option) {
-                (Some(group), Some(user)) => Some(gitlab::add_target_project_member_to_group(
-                    group.clone(),
-                    user.clone(),
-                    member,
-                )),
-                _ => None,
+    let mut usernames = HashMap::new();
+    let levels = environments
+        .values_mut()
+        .flat_map(|x| x.protected_environments.iter_mut())
+        .flat_map(|x| x.deploy_access_levels.iter_mut());
+    for level in levels {
+        if let Some(user_id) = level.user_id {
+            if let Entry::Vacant(entry) = usernames.entry(user_id) {
+                let user = gitlab::fetch_source_user(user_id).await?;
+                entry.insert(user.map(|x| x.username));
             }
-        })
-        .collect();
-    http::politely_try_join_all(futures, 8, 500).await?;
+            level.username = usernames.get(&user_id).cloned().flatten();
+        }
+        if let Some(group_id) = level.group_id {
+            level.group_full_path = group_paths.get(&group_id).cloned();
+        }
+    }
+    save_source_environments(&environments)?;
+    Ok(())
+}
+
+fn save_source_environments(environments: &CachedEnvironments) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/environments.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &environments)?;
+    println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
+pub async fn fetch_source_environments(
+    project: &SourceProject,
+) -> Result<(String, SourceProjectEnvironments), Box<dyn Error>> {
+    let key = project.key();
+    let environments = gitlab::fetch_source_environments(project).await?;
+    Ok((key, environments))
+}
+
 // ---------------------------------------------------------------------------
-// Delete Target Projects
+// Download Source CI Variables
 // ---------------------------------------------------------------------------
-pub async fn delete_target_projects() -> Result<(), Box<dyn Error>> {
-    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
-    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
-    let project_paths: Vec<_> = metadata
-        .values()
-        .map(|project| project.path_with_namespace.to_string())
-        .collect();
+pub async fn download_source_ci_variables() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let projects: Vec<_> = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects.iter().map(fetch_source_ci_variables).collect();
+    let pairs = http::politely_try_join_all(futures, 24, 500).await?;
+    let all_ci_variables: HashMap<_, _> = pairs.into_iter().collect();
+    save_ci_variables(&all_ci_variables)?;
+    Ok(())
+}
 
-    let all_projects = gitlab::fetch_all_target_projects().await?;
-    let futures: Vec<_> = all_projects
-        .into_iter()
-        .filter(|project| project_paths.contains(&project.path_with_namespace))
-        .map(gitlab::delete_target_project)
-        .collect();
-    http::politely_try_join_all(futures, 8, 500).await?;
+fn save_ci_variables(ci_variables: &CachedCiVariables) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/ci_variables.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &ci_variables)?;
+    println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
+pub async fn fetch_source_ci_variables(
+    project: &SourceProject,
+) -> Result<(String, Vec<SourceVariable>), Box<dyn Error>> {
+    let key = project.key();
+    let variables = gitlab::fetch_source_ci_variables(project).await?;
+    Ok((key, variables))
+}
+
 // ---------------------------------------------------------------------------
-// Import Target Projects
+// Download Source Boards
 // ---------------------------------------------------------------------------
-pub async fn import_target_projects() -> Result<(), Box<dyn Error>> {
-    let metadata = std::fs::read_to_string("cache/project_metadata.json")?;
-    let metadata: CachedProjectMetadata = serde_json::from_str(&metadata)?;
-
-    let existing_projects = gitlab::fetch_all_target_projects().await?;
-    let existing_paths: Vec<_> = existing_projects
-        .into_iter()
-        .map(|project| project.path_with_namespace)
+pub async fn download_source_boards() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups
+        .iter()
+        .map(|group| fetch_source_boards(Membership::Group(group.clone())))
         .collect();
+    let mut failed = vec![];
+    let group_boards = collect_source_boards(
+        http::politely_try_join_all(futures, 24, 500).await?,
+        &mut failed,
+    );
 
-    let remaining_projects: Vec<_> = metadata
+    let projects = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects
         .into_iter()
-        .map(|(_, project)| project)
-        .filter(|project| !existing_paths.contains(&project.path_with_namespace))
+        .map(|project| fetch_source_boards(Membership::Project(project)))
         .collect();
-    let num_remaining = remaining_projects.len();
-    for (index, project) in remaining_projects.into_iter().enumerate() {
-        let _ = gitlab::import_target_project(project).await;
-        println!("Num. remaining projects: {}", num_remaining - index - 1);
-        http::throttle_for_ms(10 * 1000);
+    let project_boards = collect_source_boards(
+        http::politely_try_join_all(futures, 24, 500).await?,
+        &mut failed,
+    );
+
+    let all_boards = HashMap::from([
+        ("groups".to_string(), group_boards),
+        ("projects".to_string(), project_boards),
+    ]);
+    save_source_boards(&all_boards)?;
+
+    if !failed.is_empty() {
+        failed.sort();
+        println!(
+            "Could not read the boards of the following groups and projects:\n{:#?}",
+            failed
+        );
+    }
+    Ok(())
+}
+
+fn collect_source_boards(
+    pairs: Vec<(String, Option<Vec<SourceBoard>>)>,
+    failed: &mut Vec<String>,
+) -> HashMap<String, Vec<SourceBoard>> {
+    let mut all_boards = HashMap::new();
+    for (key, boards) in pairs {
+        match boards {
+            Some(boards) => {
+                all_boards.insert(key, boards);
+            }
+            None => failed.push(key),
+        }
     }
+    all_boards
+}
 
+fn save_source_boards(boards: &CachedBoards) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/boards.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &boards)?;
+    println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
+pub async fn fetch_source_boards(
+    membership: Membership,
+) -> Result<(String, Option<Vec<SourceBoard>>), Box<dyn Error>> {
+    let key = membership.key();
+    match gitlab::fetch_all_source_boards(membership).await {
+        Ok(boards) => Ok((key, Some(boards))),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!("Context: \n{}\n__________", key);
+            Ok((key, None))
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
-// Delete Target Users
+// Download Source Integrations
 // ---------------------------------------------------------------------------
-pub async fn delete_target_users() -> Result<(), Box<dyn Error>> {
-    let memberships = std::fs::read_to_string("cache/memberships.json")?;
-    let memberships: CachedMemberships = serde_json::from_str(&memberships)?;
-    let usernames: Vec<_> = memberships
-        .values()
-        .flat_map(|user| user.values())
-        .flatten()
-        .unique_by(|user| user.id)
-        .map(|user| user.username.to_string())
-        .collect();
+pub async fn download_source_integrations() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let projects: Vec<_> = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects.iter().map(fetch_source_integrations).collect();
+    let pairs = http::politely_try_join_all(futures, 24, 500).await?;
+    let mut all_integrations = HashMap::new();
+    let mut failed = vec![];
+    for (key, integrations) in pairs {
+        match integrations {
+            Some(integrations) => {
+                all_integrations.insert(key, integrations);
+            }
+            None => failed.push(key),
+        }
+    }
+    save_source_integrations(&all_integrations)?;
 
-    let all_target_users = gitlab::fetch_all_target_users().await?;
-    let futures: Vec<_> = all_target_users
-        .into_iter()
-        .filter(|user| usernames.contains(&user.username))
-        .map(gitlab::delete_target_user)
-        .collect();
-    http::politely_try_join_all(futures, 8, 500).await?;
+    if !failed.is_empty() {
+        failed.sort();
+        println!(
+            "Could not read the integrations of the following projects:\n{:#?}",
+            failed
+        );
+    }
+    Ok(())
+}
+
+fn save_source_integrations(integrations: &CachedIntegrations) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/integrations.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &integrations)?;
+    println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
+pub async fn fetch_source_integrations(
+    project: &SourceProject,
+) -> Result<(String, Option<Vec<SourceIntegration>>), Box<dyn Error>> {
+    let key = project.key();
+    match gitlab::fetch_source_integrations(project).await {
+        Ok(integrations) => Ok((key, Some(integrations))),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!("Context: \n{}\n__________", key);
+            Ok((key, None))
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
-// Create Target Users
+// Download Source Runners
 // ---------------------------------------------------------------------------
-pub async fn create_target_users() -> Result<(), Box<dyn Error>> {
-    let email_mapping = std::fs::read_to_string("cache/username_email_mapping.json")?;
-    let email_mapping: HashMap<String, String> = serde_json::from_str(&email_mapping)?;
-    println!(
-        "Using the following username-to-email mapping:\n{:#?}",
-        email_mapping
-    );
-
-    let existing_users = gitlab::fetch_all_target_users().await?;
-    let existing_usernames: Vec<_> = existing_users
+pub async fn download_source_runners() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let futures: Vec<_> = groups
+        .iter()
+        .map(|group| fetch_source_runners(Membership::Group(group.clone())))
+        .collect();
+    let group_runners: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
         .into_iter()
-        .map(|user| user.username)
         .collect();
 
-    let users_to_create = load_users_to_create()?;
-    let futures: Vec<_> = users_to_create
+    let projects = gitlab::fetch_all_source_projects(groups).await?;
+    let futures: Vec<_> = projects
         .into_iter()
-        .filter(|user| !existing_usernames.contains(&user.username))
-        .map(|user| gitlab::create_target_user(user, &email_mapping))
+        .map(|project| fetch_source_runners(Membership::Project(project)))
         .collect();
-    println!("Creating target users for {} users...", futures.len());
-    http::politely_try_join_all(futures, 8, 500).await?;
+    let project_runners: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+
+    let all_runners = HashMap::from([
+        ("groups".to_string(), group_runners),
+        ("projects".to_string(), project_runners),
+    ]);
+    save_source_runners(&all_runners)?;
     Ok(())
 }
 
-pub fn load_users_to_create() -> Result<Vec<SourceUser>, Box<dyn Error>> {
-    let memberships = std::fs::read_to_string("cache/memberships.json")?;
-    let memberships: CachedMemberships = serde_json::from_str(&memberships)?;
-    let users_from_memberships = memberships
-        .values()
-        .flat_map(|member| member.values())
-        .flatten()
-        .map(|user| user.to_user());
-
-    let issues = std::fs::read_to_string("cache/issues.json")?;
-    let issues: CachedIssues = serde_json::from_str(&issues)?;
-    let users_from_issues = issues.values().flat_map(|project_issues| {
-        let users: Vec<_> = project_issues
-            .iter()
-            .flat_map(|issue| {
-                let author = issue.author.clone();
-                let assignee = issue.assignee.clone();
-                match assignee {
-                    Some(assignee) => vec![assignee, author],
-                    None => vec![author],
-                }
-            })
-            .collect();
-        users
-    });
+fn save_source_runners(runners: &CachedRunners) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/runners.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &runners)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
 
-    let users_to_create = users_from_memberships
-        .chain(users_from_issues)
-        .unique_by(|user| user.id)
-        .collect();
-    Ok(users_to_create)
+pub async fn fetch_source_runners(
+    membership: Membership,
+) -> Result<(String, Vec<SourceRunner>), Box<dyn Error>> {
+    let key = membership.key();
+    let runners = gitlab::fetch_source_runners(membership).await?;
+    Ok((key, runners))
 }
 
 // ---------------------------------------------------------------------------
-// Download Source CI Variables
+// Download Source Triggers
 // ---------------------------------------------------------------------------
-pub async fn download_source_ci_variables() -> Result<(), Box<dyn Error>> {
+pub async fn download_source_triggers() -> Result<(), Box<dyn Error>> {
     let groups = gitlab::fetch_all_source_groups().await?;
     let projects: Vec<_> = gitlab::fetch_all_source_projects(groups).await?;
-    let futures: Vec<_> = projects.iter().map(fetch_source_ci_variables).collect();
+    let futures: Vec<_> = projects.iter().map(fetch_source_triggers).collect();
     let pairs = http::politely_try_join_all(futures, 24, 500).await?;
-    let all_ci_variables: HashMap<_, _> = pairs.into_iter().collect();
-    save_ci_variables(&all_ci_variables)?;
+    let all_triggers: HashMap<_, _> = pairs.into_iter().collect();
+    save_source_triggers(&all_triggers)?;
     Ok(())
 }
 
-fn save_ci_variables(ci_variables: &CachedCiVariables) -> Result<(), Box<dyn Error>> {
+fn save_source_triggers(triggers: &CachedTriggers) -> Result<(), Box<dyn Error>> {
     let dir_path = "cache";
     std::fs::create_dir_all(dir_path)?;
-    let json_path = format!("{}/ci_variables.json", dir_path);
-    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &ci_variables)?;
+    let json_path = format!("{}/triggers.json", dir_path);
+    serde_json::to_writer_pretty(&create_protected_file(&json_path)?, &triggers)?;
     println!("Successfully wrote to {}!", json_path);
     Ok(())
 }
 
-pub async fn fetch_source_ci_variables(
+pub async fn fetch_source_triggers(
     project: &SourceProject,
-) -> Result<(String, Vec<SourceVariable>), Box<dyn Error>> {
+) -> Result<(String, Vec<SourceTrigger>), Box<dyn Error>> {
     let key = project.key();
-    let variables = gitlab::fetch_source_ci_variables(project).await?;
-    Ok((key, variables))
+    let triggers = gitlab::fetch_all_source_triggers(project).await?;
+    Ok((key, triggers))
 }
 
 // ---------------------------------------------------------------------------
@@ -450,6 +3524,42 @@ pub async fn fetch_all_source_issues(
     Ok((key, issues))
 }
 
+// ---------------------------------------------------------------------------
+// Download Source Merge Requests
+// ---------------------------------------------------------------------------
+pub async fn download_source_merge_requests() -> Result<(), Box<dyn Error>> {
+    let groups = gitlab::fetch_all_source_groups().await?;
+    let projects: Vec<_> = gitlab::fetch_all_source_projects(groups).await?;
+
+    let futures: Vec<_> = projects
+        .into_iter()
+        .map(fetch_all_source_merge_requests)
+        .collect();
+    let merge_requests: HashMap<_, _> = http::politely_try_join_all(futures, 24, 500)
+        .await?
+        .into_iter()
+        .collect();
+    save_source_merge_requests(&merge_requests)?;
+    Ok(())
+}
+
+fn save_source_merge_requests(merge_requests: &CachedMergeRequests) -> Result<(), Box<dyn Error>> {
+    let dir_path = "cache";
+    std::fs::create_dir_all(dir_path)?;
+    let json_path = format!("{}/merge_requests.json", dir_path);
+    serde_json::to_writer_pretty(&std::fs::File::create(&json_path)?, &merge_requests)?;
+    println!("Successfully wrote to {}!", json_path);
+    Ok(())
+}
+
+pub async fn fetch_all_source_merge_requests(
+    project: SourceProject,
+) -> Result<(String, Vec<SourceMergeRequest>), Box<dyn Error>> {
+    let key = project.key();
+    let merge_requests = gitlab::fetch_all_source_merge_requests(&project).await?;
+    Ok((key, merge_requests))
+}
+
 // ---------------------------------------------------------------------------
 // Download Source Projects
 // ---------------------------------------------------------------------------
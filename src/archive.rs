@@ -0,0 +1,56 @@
+use crate::types::ExportMember;
+use flate2::read::GzDecoder;
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::{BufRead, BufReader};
+
+const CONTRIBUTION_FILES: [&str; 2] = ["issues.ndjson", "merge_requests.ndjson"];
+
+pub struct ExportContributors {
+    pub members: Vec<ExportMember>,
+    pub author_ids: HashSet<u32>,
+}
+
+pub fn read_export_contributors(gz_path: &str) -> Result<ExportContributors, Box<dyn Error>> {
+    let file = std::fs::File::open(gz_path)?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    let mut members = vec![];
+    let mut author_ids = HashSet::new();
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let path = entry.path()?.to_string_lossy().to_string();
+        if !path.contains("tree/project/") {
+            continue;
+        }
+        if path.ends_with("project_members.ndjson") {
+            for line in BufReader::new(entry).lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    members.push(serde_json::from_str(&line)?);
+                }
+            }
+        } else if CONTRIBUTION_FILES.iter().any(|x| path.ends_with(x)) {
+            for line in BufReader::new(entry).lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    let record: serde_json::Value = serde_json::from_str(&line)?;
+                    collect_author_ids(&record, &mut author_ids);
+                }
+            }
+        }
+    }
+    Ok(ExportContributors {
+        members,
+        author_ids,
+    })
+}
+
+fn collect_author_ids(record: &serde_json::Value, author_ids: &mut HashSet<u32>) {
+    if let Some(id) = record.get("author_id").and_then(|x| x.as_u64()) {
+        author_ids.insert(id as u32);
+    }
+    let notes = record.get("notes").and_then(|x| x.as_array());
+    for note in notes.into_iter().flatten() {
+        collect_author_ids(note, author_ids);
+    }
+}
@@ -0,0 +1,38 @@
+use openssl::hash::MessageDigest;
+use openssl::symm::Cipher;
+use std::error::Error;
+
+const MAGIC: &[u8] = b"Salted__";
+const ITERATIONS: usize = 100_000;
+
+// Same format as `openssl enc -aes-256-cbc -pbkdf2 -iter 100000`, so the
+// mapping file can also be decrypted without this tool.
+pub fn encrypt(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut salt = [0; 8];
+    openssl::rand::rand_bytes(&mut salt)?;
+    let (key, iv) = derive_key(passphrase, &salt)?;
+    let ciphertext = openssl::symm::encrypt(Cipher::aes_256_cbc(), &key, Some(&iv), plaintext)?;
+    Ok([MAGIC, &salt, &ciphertext].concat())
+}
+
+pub fn decrypt(encrypted: &[u8], passphrase: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    if encrypted.len() < 16 || !encrypted.starts_with(MAGIC) {
+        return Err("Encrypted file is missing its salt header!".into());
+    }
+    let (key, iv) = derive_key(passphrase, &encrypted[8..16])?;
+    let plaintext =
+        openssl::symm::decrypt(Cipher::aes_256_cbc(), &key, Some(&iv), &encrypted[16..])?;
+    Ok(plaintext)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Box<dyn Error>> {
+    let mut derived = [0; 48];
+    openssl::pkcs5::pbkdf2_hmac(
+        passphrase.as_bytes(),
+        salt,
+        ITERATIONS,
+        MessageDigest::sha256(),
+        &mut derived,
+    )?;
+    Ok((derived[..32].to_vec(), derived[32..].to_vec()))
+}
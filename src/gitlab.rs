@@ -1,11 +1,23 @@
 use crate::types::{
-    ExportStatus, Membership, SourceGroup, SourceIssue, SourceMember, SourcePipelineSchedule,
-    SourcePipelineScheduleWithoutVariables, SourceProject, SourceUser, SourceVariable, TargetGroup,
-    TargetPipelineSchedule, TargetProject, TargetUser,
+    ExportStatus, GraphqlTimelog, Issuable, Membership, ProjectSettings, ProjectStatistics,
+    ProjectWithStatistics, ProtectedBranch, RepositoryRef, SourceAccessToken, SourceApprovalRule,
+    SourceApprovalSettings, SourceBadge, SourceBoard, SourceEnvironment, SourceEpic,
+    SourceEpicIssue, SourceFreezePeriod, SourceGpgKey, SourceGroup, SourceIntegration,
+    SourceIntegrationWithoutProperties, SourceIssue, SourceLabel, SourceMember, SourceMergeRequest,
+    SourceMilestone, SourcePackage, SourcePackageFile, SourcePipelineSchedule,
+    SourcePipelineScheduleWithoutVariables, SourcePipelineVariable, SourceProject,
+    SourceProjectApprovals, SourceProjectEnvironments, SourceProtectedEnvironment,
+    SourceRegistryRepository, SourceRegistryTag, SourceRunner, SourceRunnerWithoutDetails,
+    SourceSnippet, SourceSshKey, SourceTrigger, SourceUser, SourceUserKeys, SourceUserProfile,
+    SourceVariable, TargetAccessToken, TargetApprovalRule, TargetBadge, TargetBoard,
+    TargetBoardList, TargetEnvironment, TargetEpic, TargetFreezePeriod, TargetGpgKey, TargetGroup,
+    TargetIntegration, TargetIssuable, TargetIssue, TargetLabel, TargetMembership, TargetMilestone,
+    TargetPackage, TargetPipelineSchedule, TargetProject, TargetProtectedEnvironment, TargetRunner,
+    TargetSnippet, TargetSshKey, TargetTrigger, TargetUser, Timelog, UserIdentity,
 };
 use crate::{env, http};
 use reqwest::Response;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 
 lazy_static::lazy_static! {
@@ -15,15 +27,32 @@ lazy_static::lazy_static! {
     pub static ref TARGET_GITLAB_TOKEN: String = env::load_env("TARGET_GITLAB_TOKEN");
 }
 
-pub async fn delete_target_pipeline_schedules(
+pub async fn fetch_all_target_pipeline_schedules(
     project: &TargetProject,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<Vec<TargetPipelineSchedule>, Box<dyn Error>> {
+    let mut all_schedules = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut schedules = fetch_target_pipeline_schedules(project, latest_page).await?;
+        latest_len = schedules.len();
+        latest_page += 1;
+        all_schedules.append(&mut schedules);
+    }
+    Ok(all_schedules)
+}
+
+async fn fetch_target_pipeline_schedules(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetPipelineSchedule>, Box<dyn Error>> {
     let url = format!(
         "{}/projects/{}/pipeline_schedules",
         *TARGET_GITLAB_URL, project.id
     );
     let payload = http::CLIENT
         .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
         .await?
@@ -31,7 +60,13 @@ pub async fn delete_target_pipeline_schedules(
         .text()
         .await?;
     let schedules: Vec<TargetPipelineSchedule> = serde_json::from_str(&payload)?;
+    Ok(schedules)
+}
 
+pub async fn delete_target_pipeline_schedules(
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    let schedules = fetch_all_target_pipeline_schedules(project).await?;
     for schedule in schedules {
         println!(
             "Deleting pipeline schedule '{}' in {}...",
@@ -51,160 +86,143 @@ pub async fn delete_target_pipeline_schedules(
     Ok(())
 }
 
-pub async fn create_target_ci_variable(
-    variable: SourceVariable,
-    project: &TargetProject,
-) -> Result<(), Box<dyn Error>> {
-    println!("Creating variable {} in {}...", variable.key, project.key());
-    let url = format!("{}/projects/{}/variables", *TARGET_GITLAB_URL, project.id);
-    let response = http::CLIENT
-        .post(url)
-        .form(&[
-            ("key", variable.key),
-            ("value", variable.value),
-            ("variable_type", variable.variable_type),
-            ("protected", variable.protected.to_string()),
-            ("masked", variable.masked.to_string()),
-        ])
-        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
-        .send()
-        .await?;
-    if let Err(err) = response.error_for_status() {
-        println!("{}", err);
+pub async fn fetch_all_target_access_tokens(
+    membership: &TargetMembership,
+) -> Result<Vec<TargetAccessToken>, Box<dyn Error>> {
+    let mut all_tokens = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut tokens = fetch_target_access_tokens(membership, latest_page).await?;
+        latest_len = tokens.len();
+        latest_page += 1;
+        all_tokens.append(&mut tokens);
     }
-    Ok(())
+    Ok(all_tokens)
 }
 
-pub async fn create_target_pipeline_schedules(
-    schedules: Vec<SourcePipelineSchedule>,
-    project: &TargetProject,
-) -> Result<(), Box<dyn Error>> {
-    println!(
-        "Creating schedules {:#?} in {}...",
-        schedules,
-        project.key()
+async fn fetch_target_access_tokens(
+    membership: &TargetMembership,
+    page: u32,
+) -> Result<Vec<TargetAccessToken>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/access_tokens",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
     );
-    for schedule in schedules {
-        let url = format!(
-            "{}/projects/{}/pipeline_schedules",
-            *TARGET_GITLAB_URL, project.id
-        );
-        let result = http::CLIENT
-            .post(url)
-            .form(&[
-                ("description", schedule.description),
-                ("ref", schedule.ref_),
-                ("cron", schedule.cron),
-                ("cron_timezone", schedule.cron_timezone),
-                ("active", schedule.active.to_string()),
-            ])
-            .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
-            .send()
-            .await?
-            .error_for_status();
-        match result {
-            Ok(response) => {
-                let payload = response.text().await?;
-                let created: TargetPipelineSchedule = serde_json::from_str(&payload)?;
-                println!(
-                    "Created pipeline schedule '{}' in {}!",
-                    created.description,
-                    project.key()
-                );
-
-                for variable in schedule.variables.unwrap_or_default() {
-                    let url = format!(
-                        "{}/projects/{}/pipeline_schedules/{}/variables",
-                        *TARGET_GITLAB_URL, project.id, created.id
-                    );
-                    http::CLIENT
-                        .post(url)
-                        .form(&[
-                            ("key", variable.key),
-                            ("value", variable.value),
-                            ("variable_type", variable.variable_type),
-                        ])
-                        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
-                        .send()
-                        .await?;
-                }
-            }
-            Err(err) => println!("{:#?}", err),
-        }
-    }
-
-    Ok(())
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let tokens: Vec<TargetAccessToken> = serde_json::from_str(&payload)?;
+    Ok(tokens)
 }
 
-pub async fn reassign_target_issue(
-    issue: SourceIssue,
-    project: &TargetProject,
-    assignee: &TargetUser,
-) -> Result<(), Box<dyn Error>> {
+pub async fn create_target_access_token(
+    token: &SourceAccessToken,
+    membership: &TargetMembership,
+) -> Result<Option<TargetAccessToken>, Box<dyn Error>> {
     println!(
-        "Reassigning issue\n{:?}\nin project\n{:?}\nto\n{:?}\n__________",
-        issue, project, assignee
+        "Creating access token '{}' in {}...",
+        token.name,
+        membership.key()
     );
     let url = format!(
-        "{}/projects/{}/issues/{}",
-        *TARGET_GITLAB_URL, project.id, issue.iid
+        "{}/{}/{}/access_tokens",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
     );
-    let response = http::CLIENT
-        .put(url)
-        .form(&[("assignee_id", assignee.id)])
+    let mut form = vec![
+        ("name", token.name.to_owned()),
+        ("access_level", token.access_level.to_string()),
+    ];
+    for scope in &token.scopes {
+        form.push(("scopes[]", scope.to_owned()));
+    }
+    if let Some(expires_at) = &token.expires_at {
+        form.push(("expires_at", expires_at.to_owned()));
+    }
+    let result = http::CLIENT
+        .post(url)
+        .form(&form)
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
-        .await?;
-    if let Err(err) = response.error_for_status() {
-        println!("Error: {}", err);
-        println!(
-            "Context: \n{:?}\nin project\n{:?}\nto\n{:?}\n__________",
-            issue, project, assignee
-        )
+        .await?
+        .error_for_status();
+    match result {
+        Ok(response) => {
+            let payload = response.text().await?;
+            let created: TargetAccessToken = serde_json::from_str(&payload)?;
+            Ok(Some(created))
+        }
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{:?}\nin\n{}\n__________",
+                token,
+                membership.key()
+            );
+            Ok(None)
+        }
     }
-    Ok(())
 }
 
-pub async fn add_target_project_member_to_project(
-    project: TargetProject,
-    user: TargetUser,
-    member: SourceMember,
-) -> Result<(), Box<dyn Error>> {
-    println!(
-        "Adding user {:?} to project {:?} from access level {:?}...",
-        user, project, member.access_level
-    );
-    let url = format!("{}/projects/{}/members", *TARGET_GITLAB_URL, project.id);
-    let response = http::CLIENT
-        .post(url)
-        .form(&[
-            ("user_id", &user.id.to_string()),
-            ("access_level", &member.access_level.to_string()),
+pub async fn fetch_all_target_group_labels(
+    group: &TargetGroup,
+) -> Result<Vec<TargetLabel>, Box<dyn Error>> {
+    let mut all_labels = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut labels = fetch_target_group_labels(group, latest_page).await?;
+        latest_len = labels.len();
+        latest_page += 1;
+        all_labels.append(&mut labels);
+    }
+    Ok(all_labels)
+}
+
+async fn fetch_target_group_labels(
+    group: &TargetGroup,
+    page: u32,
+) -> Result<Vec<TargetLabel>, Box<dyn Error>> {
+    let url = format!("{}/groups/{}/labels", *TARGET_GITLAB_URL, group.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("include_ancestor_groups", "false"),
         ])
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
+        .await?
+        .error_for_status()?
+        .text()
         .await?;
-    if let Err(err) = response.error_for_status() {
-        println!("{}", err);
-    }
-    Ok(())
+    let labels: Vec<TargetLabel> = serde_json::from_str(&payload)?;
+    Ok(labels)
 }
 
-pub async fn add_target_project_member_to_group(
-    group: TargetGroup,
-    user: TargetUser,
-    member: SourceMember,
+pub async fn create_target_group_label(
+    label: SourceLabel,
+    group: &TargetGroup,
 ) -> Result<(), Box<dyn Error>> {
-    println!(
-        "Adding user {:?} to group {:?} from access level {:?}...",
-        user, group, member.access_level
-    );
-    let url = format!("{}/groups/{}/members", *TARGET_GITLAB_URL, group.id);
+    println!("Creating label '{}' in {}...", label.name, group.key());
+    let url = format!("{}/groups/{}/labels", *TARGET_GITLAB_URL, group.id);
     let response = http::CLIENT
         .post(url)
         .form(&[
-            ("user_id", &user.id.to_string()),
-            ("access_level", &member.access_level.to_string()),
+            ("name", label.name),
+            ("color", label.color),
+            ("description", label.description.unwrap_or_default()),
         ])
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
@@ -215,235 +233,3497 @@ pub async fn add_target_project_member_to_group(
     Ok(())
 }
 
-pub async fn fetch_all_target_groups() -> Result<Vec<TargetGroup>, Box<dyn Error>> {
-    let mut all_groups = vec![];
+pub async fn fetch_all_target_group_milestones(
+    group: &TargetGroup,
+) -> Result<Vec<TargetMilestone>, Box<dyn Error>> {
+    let mut all_milestones = vec![];
     let mut latest_page = 1;
     let mut latest_len = 0;
     while latest_len == 100 || latest_page == 1 {
-        let mut groups = fetch_target_groups(latest_page).await?;
-        latest_len = groups.len();
+        let mut milestones = fetch_target_group_milestones(group, latest_page).await?;
+        latest_len = milestones.len();
         latest_page += 1;
-        all_groups.append(&mut groups);
+        all_milestones.append(&mut milestones);
     }
-    Ok(all_groups)
+    Ok(all_milestones)
 }
 
-async fn fetch_target_groups(page: u32) -> Result<Vec<TargetGroup>, Box<dyn Error>> {
-    let url = format!("{}/groups/", *TARGET_GITLAB_URL);
-    let response = http::CLIENT
+async fn fetch_target_group_milestones(
+    group: &TargetGroup,
+    page: u32,
+) -> Result<Vec<TargetMilestone>, Box<dyn Error>> {
+    let url = format!("{}/groups/{}/milestones", *TARGET_GITLAB_URL, group.id);
+    let payload = http::CLIENT
         .get(url)
         .query(&[("per_page", "100"), ("page", &page.to_string())])
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
+        .await?
+        .error_for_status()?
+        .text()
         .await?;
-    let payload = &response.text().await?;
-    let groups: Vec<TargetGroup> = serde_json::from_str(payload)?;
-    Ok(groups)
+    let milestones: Vec<TargetMilestone> = serde_json::from_str(&payload)?;
+    Ok(milestones)
 }
 
-pub async fn delete_target_project(project: TargetProject) -> Result<(), Box<dyn Error>> {
-    println!("Deleting project {:?}...", project);
-    let url = format!("{}/projects/{}", *TARGET_GITLAB_URL, project.id);
-    http::CLIENT
-        .delete(url)
+pub async fn create_target_group_milestone(
+    milestone: SourceMilestone,
+    group: &TargetGroup,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating milestone '{}' in {}...",
+        milestone.title,
+        group.key()
+    );
+    let url = format!("{}/groups/{}/milestones", *TARGET_GITLAB_URL, group.id);
+    let mut form = vec![
+        ("title", milestone.title.to_owned()),
+        ("description", milestone.description.unwrap_or_default()),
+    ];
+    if let Some(start_date) = milestone.start_date {
+        form.push(("start_date", start_date));
+    }
+    if let Some(due_date) = milestone.due_date {
+        form.push(("due_date", due_date));
+    }
+    let result = http::CLIENT
+        .post(url)
+        .form(&form)
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
         .await?
-        .error_for_status()?;
+        .error_for_status();
+    let created: TargetMilestone = match result {
+        Ok(response) => serde_json::from_str(&response.text().await?)?,
+        Err(err) => {
+            println!("{}", err);
+            return Ok(());
+        }
+    };
+
+    if milestone.state == "closed" {
+        let url = format!(
+            "{}/groups/{}/milestones/{}",
+            *TARGET_GITLAB_URL, group.id, created.id
+        );
+        let response = http::CLIENT
+            .put(url)
+            .form(&[("state_event", "close")])
+            .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+            .send()
+            .await?;
+        if let Err(err) = response.error_for_status() {
+            println!("{}", err);
+        }
+    }
     Ok(())
 }
 
-pub async fn fetch_all_target_projects() -> Result<Vec<TargetProject>, Box<dyn Error>> {
-    let mut all_projects = vec![];
+pub async fn fetch_all_target_epics(
+    group: &TargetGroup,
+) -> Result<Vec<TargetEpic>, Box<dyn Error>> {
+    let mut all_epics = vec![];
     let mut latest_page = 1;
     let mut latest_len = 0;
     while latest_len == 100 || latest_page == 1 {
-        let mut projects = fetch_target_projects(latest_page).await?;
-        latest_len = projects.len();
+        let mut epics = fetch_target_epics(group, latest_page).await?;
+        latest_len = epics.len();
         latest_page += 1;
-        all_projects.append(&mut projects);
+        all_epics.append(&mut epics);
     }
-    Ok(all_projects)
+    Ok(all_epics)
 }
 
-pub async fn fetch_target_projects(page: u32) -> Result<Vec<TargetProject>, Box<dyn Error>> {
-    let url = format!("{}/projects", *TARGET_GITLAB_URL);
-    let response = http::CLIENT
+async fn fetch_target_epics(
+    group: &TargetGroup,
+    page: u32,
+) -> Result<Vec<TargetEpic>, Box<dyn Error>> {
+    let url = format!("{}/groups/{}/epics", *TARGET_GITLAB_URL, group.id);
+    let payload = http::CLIENT
         .get(url)
-        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("include_ancestor_groups", "false"),
+            ("include_descendant_groups", "false"),
+        ])
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
         .send()
         .await?
-        .error_for_status()?;
-    let payload = &response.text().await?;
-    let projects: Vec<TargetProject> = serde_json::from_str(payload)?;
-    Ok(projects)
-}
-
-pub async fn import_target_project(project: SourceProject) -> Result<(), String> {
-    let spawn_result =
-        tokio::task::spawn_blocking(move || match synchronous_import_target_project(project) {
-            Ok(x) => Ok(x),
-            Err(err) => {
-                println!("{:#?}", err);
-                Err("Failed to import target project!".to_owned())
-            }
-        })
-        .await;
-    spawn_result.map_err(|_| "Spawn blocking failed!".to_string())?
+        .error_for_status()?
+        .text()
+        .await?;
+    let epics: Vec<TargetEpic> = serde_json::from_str(&payload)?;
+    Ok(epics)
 }
 
-pub fn synchronous_import_target_project(project: SourceProject) -> Result<(), Box<dyn Error>> {
-    println!("Importing project {:?}...", project);
-    let gz_path = format!("cache/projects/{}.gz", project.id);
-    let namespace = parse_namespace(&project);
-    let form = reqwest::blocking::multipart::Form::new()
-        .text("namespace", namespace)
-        .text("name", project.name)
-        .text("path", project.path)
-        .file("file", gz_path)?;
-
-    let client = reqwest::blocking::Client::builder()
-        .timeout(std::time::Duration::from_secs(900))
-        .build()?;
-    let url = format!("{}/projects/import", *TARGET_GITLAB_URL);
-    client
+pub async fn create_target_epic(
+    epic: SourceEpic,
+    group: &TargetGroup,
+    parent: Option<&TargetEpic>,
+) -> Result<Option<(u32, TargetEpic)>, Box<dyn Error>> {
+    println!("Creating epic '{}' in {}...", epic.title, group.key());
+    let url = format!("{}/groups/{}/epics", *TARGET_GITLAB_URL, group.id);
+    let mut form = vec![
+        ("title", epic.title.to_owned()),
+        ("description", epic.description.unwrap_or_default()),
+        ("labels", epic.labels.join(",")),
+        ("created_at", epic.created_at.to_owned()),
+        ("start_date_is_fixed", epic.start_date_is_fixed.to_string()),
+        ("due_date_is_fixed", epic.due_date_is_fixed.to_string()),
+    ];
+    if let Some(start_date) = epic.start_date_fixed.filter(|_| epic.start_date_is_fixed) {
+        form.push(("start_date_fixed", start_date));
+    }
+    if let Some(due_date) = epic.due_date_fixed.filter(|_| epic.due_date_is_fixed) {
+        form.push(("due_date_fixed", due_date));
+    }
+    if let Some(parent) = parent {
+        form.push(("parent_id", parent.id.to_string()));
+    }
+    let result = http::CLIENT
         .post(url)
+        .form(&form)
         .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
-        .multipart(form)
-        .send()?
-        .error_for_status()?;
-    Ok(())
-}
+        .send()
+        .await?
+        .error_for_status();
+    let created: TargetEpic = match result {
+        Ok(response) => serde_json::from_str(&response.text().await?)?,
+        Err(err) => {
+            println!("Error: {}", err);
+            println!("Context: \n{}\nin\n{}\n__________", epic.title, group.key());
+            return Ok(None);
+        }
+    };
 
-fn parse_namespace(project: &SourceProject) -> String {
-    let mut path = project.path_with_namespace.split('/').rev();
-    path.next();
-    path.rev().fold(String::new(), |x, y| {
-        if x.is_empty() {
-            y.to_string()
-        } else {
-            x + "/" + y
+    if epic.state == "closed" {
+        let url = format!(
+            "{}/groups/{}/epics/{}",
+            *TARGET_GITLAB_URL, group.id, created.iid
+        );
+        let response = http::CLIENT
+            .put(url)
+            .form(&[("state_event", "close")])
+            .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+            .send()
+            .await?;
+        if let Err(err) = response.error_for_status() {
+            println!("{}", err);
         }
-    })
+    }
+    Ok(Some((epic.id, created)))
 }
 
-pub async fn fetch_all_target_users() -> Result<Vec<TargetUser>, Box<dyn Error>> {
-    let mut all_users = vec![];
-    let mut latest_page = 1;
-    let mut latest_len = 0;
+pub async fn fetch_target_issue(
+    project: &TargetProject,
+    iid: u32,
+) -> Result<Option<TargetIssue>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/issues/{}",
+        *TARGET_GITLAB_URL, project.id, iid
+    );
+    let response = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let issue: TargetIssue = serde_json::from_str(payload)?;
+        Ok(Some(issue))
+    } else {
+        Ok(None)
+    }
+}
+
+pub async fn fetch_target_issuable(
+    project: &TargetProject,
+    issuable: Issuable,
+    iid: u32,
+) -> Result<Option<TargetIssuable>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/{}/{}",
+        *TARGET_GITLAB_URL,
+        project.id,
+        issuable.url_prefix(),
+        iid
+    );
+    let response = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let issuable: TargetIssuable = serde_json::from_str(payload)?;
+        Ok(Some(issuable))
+    } else {
+        Ok(None)
+    }
+}
+
+pub async fn set_target_time_estimate(
+    project: &TargetProject,
+    issuable: Issuable,
+    iid: u32,
+    seconds: i64,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Setting time estimate of {}#{} in {}...",
+        issuable.url_prefix(),
+        iid,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/{}/{}/time_estimate",
+        *TARGET_GITLAB_URL,
+        project.id,
+        issuable.url_prefix(),
+        iid
+    );
+    let response = http::CLIENT
+        .post(url)
+        .form(&[("duration", format_duration(seconds))])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_target_token_username() -> Result<String, Box<dyn Error>> {
+    let url = format!("{}/user", *TARGET_GITLAB_URL);
+    let payload = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let user: TargetUser = serde_json::from_str(&payload)?;
+    Ok(user.username)
+}
+
+pub async fn fetch_target_timelogs(
+    project: &TargetProject,
+    issuable: Issuable,
+    iid: u32,
+) -> Result<Vec<GraphqlTimelog>, Box<dyn Error>> {
+    fetch_timelogs(
+        &TARGET_GITLAB_URL,
+        &TARGET_GITLAB_TOKEN,
+        &project.path_with_namespace,
+        issuable,
+        iid,
+    )
+    .await
+}
+
+// The REST API does not keep the date the time was spent at.
+pub async fn create_target_timelog(
+    timelog: &Timelog,
+    issuable: Issuable,
+    target_issuable: &TargetIssuable,
+    token: &str,
+) -> Result<(), Box<dyn Error>> {
+    let query = "mutation($input: TimelogCreateInput!) { \
+        timelogCreate(input: $input) { errors } }";
+    let variables = serde_json::json!({
+        "input": {
+            "issuableId": format!("gid://gitlab/{}/{}", issuable.graphql_name(), target_issuable.id),
+            "timeSpent": format_duration(timelog.time_spent),
+            "spentAt": timelog.spent_at,
+            "summary": timelog.summary.to_owned().unwrap_or_default(),
+        }
+    });
+    let data = graphql(&TARGET_GITLAB_URL, token, query, variables).await?;
+    check_mutation_errors(&data["timelogCreate"])
+}
+
+pub async fn delete_target_timelog(timelog: &GraphqlTimelog) -> Result<(), Box<dyn Error>> {
+    let query = "mutation($input: TimelogDeleteInput!) { \
+        timelogDelete(input: $input) { errors } }";
+    let variables = serde_json::json!({ "input": { "id": timelog.id } });
+    let data = graphql(&TARGET_GITLAB_URL, &TARGET_GITLAB_TOKEN, query, variables).await?;
+    check_mutation_errors(&data["timelogDelete"])
+}
+
+pub async fn link_target_epic_issue(
+    epic: &TargetEpic,
+    project: &TargetProject,
+    issue_iid: u32,
+) -> Result<(), Box<dyn Error>> {
+    let issue = match fetch_target_issue(project, issue_iid).await? {
+        Some(issue) => issue,
+        None => {
+            println!(
+                "Skipping missing issue #{} in {} for epic '{}'...",
+                issue_iid,
+                project.key(),
+                epic.title
+            );
+            return Ok(());
+        }
+    };
+    println!(
+        "Linking issue #{} in {} to epic '{}'...",
+        issue_iid,
+        project.key(),
+        epic.title
+    );
+    let url = format!(
+        "{}/groups/{}/epics/{}/issues/{}",
+        *TARGET_GITLAB_URL, epic.group_id, epic.iid, issue.id
+    );
+    let response = http::CLIENT
+        .post(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_badges(
+    membership: &TargetMembership,
+) -> Result<Vec<TargetBadge>, Box<dyn Error>> {
+    let mut all_badges = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut badges = fetch_target_badges(membership, latest_page).await?;
+        latest_len = badges.len();
+        latest_page += 1;
+        all_badges.append(&mut badges);
+    }
+    Ok(all_badges)
+}
+
+async fn fetch_target_badges(
+    membership: &TargetMembership,
+    page: u32,
+) -> Result<Vec<TargetBadge>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/badges",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let badges: Vec<TargetBadge> = serde_json::from_str(&payload)?;
+    Ok(badges)
+}
+
+pub async fn create_target_badge(
+    badge: SourceBadge,
+    membership: &TargetMembership,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating badge {} in {}...",
+        badge.link_url,
+        membership.key()
+    );
+    let url = format!(
+        "{}/{}/{}/badges",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let response = http::CLIENT
+        .post(url)
+        .form(&[
+            ("name", badge.name.unwrap_or_default()),
+            ("link_url", badge.link_url),
+            ("image_url", badge.image_url),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub fn rewrite_source_url(url: &str) -> String {
+    let source_web_url = web_url(&SOURCE_GITLAB_URL);
+    let target_web_url = web_url(&TARGET_GITLAB_URL);
+    url.replace(&source_web_url, &target_web_url)
+}
+
+fn web_url(api_url: &str) -> String {
+    api_url
+        .trim_end_matches('/')
+        .trim_end_matches("/api/v4")
+        .to_string()
+}
+
+pub async fn update_target_approval_settings(
+    settings: SourceApprovalSettings,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!("Updating approval settings in {}...", project.key());
+    let url = format!("{}/projects/{}/approvals", *TARGET_GITLAB_URL, project.id);
+    let form: Vec<_> = [
+        ("reset_approvals_on_push", settings.reset_approvals_on_push),
+        (
+            "disable_overriding_approvers_per_merge_request",
+            settings.disable_overriding_approvers_per_merge_request,
+        ),
+        (
+            "merge_requests_author_approval",
+            settings.merge_requests_author_approval,
+        ),
+        (
+            "merge_requests_disable_committers_approval",
+            settings.merge_requests_disable_committers_approval,
+        ),
+        (
+            "require_password_to_approve",
+            settings.require_password_to_approve,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|x| (key, x.to_string())))
+    .collect();
+    let response = http::CLIENT
+        .post(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_approval_rules(
+    project: &TargetProject,
+) -> Result<Vec<TargetApprovalRule>, Box<dyn Error>> {
+    let mut all_rules = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut rules = fetch_target_approval_rules(project, latest_page).await?;
+        latest_len = rules.len();
+        latest_page += 1;
+        all_rules.append(&mut rules);
+    }
+    Ok(all_rules)
+}
+
+async fn fetch_target_approval_rules(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetApprovalRule>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/approval_rules",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let rules: Vec<TargetApprovalRule> = serde_json::from_str(&payload)?;
+    Ok(rules)
+}
+
+pub async fn fetch_all_target_protected_branches(
+    project: &TargetProject,
+) -> Result<Vec<ProtectedBranch>, Box<dyn Error>> {
+    let mut all_branches = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut branches = fetch_target_protected_branches(project, latest_page).await?;
+        latest_len = branches.len();
+        latest_page += 1;
+        all_branches.append(&mut branches);
+    }
+    Ok(all_branches)
+}
+
+async fn fetch_target_protected_branches(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<ProtectedBranch>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/protected_branches",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let branches: Vec<ProtectedBranch> = serde_json::from_str(&payload)?;
+    Ok(branches)
+}
+
+pub async fn create_target_approval_rule(
+    rule: SourceApprovalRule,
+    project: &TargetProject,
+    users: Vec<TargetUser>,
+    groups: Vec<TargetGroup>,
+    branches: Vec<ProtectedBranch>,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating approval rule '{}' in {}...",
+        rule.name,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/approval_rules",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let mut form = vec![
+        ("name", rule.name.to_owned()),
+        ("approvals_required", rule.approvals_required.to_string()),
+    ];
+    if rule.rule_type == "any_approver" {
+        form.push(("rule_type", rule.rule_type.to_owned()));
+    }
+    for user in users {
+        form.push(("user_ids[]", user.id.to_string()));
+    }
+    for group in groups {
+        form.push(("group_ids[]", group.id.to_string()));
+    }
+    for branch in branches {
+        form.push(("protected_branch_ids[]", branch.id.to_string()));
+    }
+    let response = http::CLIENT
+        .post(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("Error: {}", err);
+        println!(
+            "Context: \n{:?}\nin project\n{}\n__________",
+            rule,
+            project.key()
+        );
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_environments(
+    project: &TargetProject,
+) -> Result<Vec<TargetEnvironment>, Box<dyn Error>> {
+    let mut all_environments = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut environments = fetch_target_environments(project, latest_page).await?;
+        latest_len = environments.len();
+        latest_page += 1;
+        all_environments.append(&mut environments);
+    }
+    Ok(all_environments)
+}
+
+async fn fetch_target_environments(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetEnvironment>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/environments",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let environments: Vec<TargetEnvironment> = serde_json::from_str(&payload)?;
+    Ok(environments)
+}
+
+pub async fn create_target_environment(
+    environment: SourceEnvironment,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating environment '{}' in {}...",
+        environment.name,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/environments",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let mut form = vec![("name", environment.name)];
+    if let Some(external_url) = environment.external_url {
+        form.push(("external_url", external_url));
+    }
+    if let Some(tier) = environment.tier {
+        form.push(("tier", tier));
+    }
+    let response = http::CLIENT
+        .post(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_protected_environments(
+    project: &TargetProject,
+) -> Result<Vec<TargetProtectedEnvironment>, Box<dyn Error>> {
+    let mut all_environments = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut environments = fetch_target_protected_environments(project, latest_page).await?;
+        latest_len = environments.len();
+        latest_page += 1;
+        all_environments.append(&mut environments);
+    }
+    Ok(all_environments)
+}
+
+async fn fetch_target_protected_environments(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetProtectedEnvironment>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/protected_environments",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let environments: Vec<TargetProtectedEnvironment> = serde_json::from_str(&payload)?;
+    Ok(environments)
+}
+
+pub async fn create_target_protected_environment(
+    environment: &SourceProtectedEnvironment,
+    deploy_access_levels: Vec<serde_json::Value>,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Protecting environment '{}' in {}...",
+        environment.name,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/protected_environments",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let body = serde_json::json!({
+        "name": environment.name,
+        "deploy_access_levels": deploy_access_levels,
+        "required_approval_count": environment.required_approval_count,
+    });
+    let response = http::CLIENT
+        .post(url)
+        .json(&body)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("Error: {}", err);
+        println!(
+            "Context: \n{:?}\nin project\n{}\n__________",
+            environment,
+            project.key()
+        );
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_freeze_periods(
+    project: &TargetProject,
+) -> Result<Vec<TargetFreezePeriod>, Box<dyn Error>> {
+    let mut all_freeze_periods = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut freeze_periods = fetch_target_freeze_periods(project, latest_page).await?;
+        latest_len = freeze_periods.len();
+        latest_page += 1;
+        all_freeze_periods.append(&mut freeze_periods);
+    }
+    Ok(all_freeze_periods)
+}
+
+async fn fetch_target_freeze_periods(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetFreezePeriod>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/freeze_periods",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let freeze_periods: Vec<TargetFreezePeriod> = serde_json::from_str(&payload)?;
+    Ok(freeze_periods)
+}
+
+pub async fn create_target_freeze_period(
+    freeze_period: SourceFreezePeriod,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating freeze period '{}'-'{}' in {}...",
+        freeze_period.freeze_start,
+        freeze_period.freeze_end,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/freeze_periods",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .post(url)
+        .form(&[
+            ("freeze_start", freeze_period.freeze_start),
+            ("freeze_end", freeze_period.freeze_end),
+            ("cron_timezone", freeze_period.cron_timezone),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_packages(
+    project: &TargetProject,
+) -> Result<Vec<TargetPackage>, Box<dyn Error>> {
+    let mut all_packages = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut packages = fetch_target_packages(project, latest_page).await?;
+        latest_len = packages.len();
+        latest_page += 1;
+        all_packages.append(&mut packages);
+    }
+    Ok(all_packages)
+}
+
+async fn fetch_target_packages(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetPackage>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/packages", *TARGET_GITLAB_URL, project.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let packages: Vec<TargetPackage> = serde_json::from_str(&payload)?;
+    Ok(packages)
+}
+
+pub async fn upload_target_package_file(
+    package: &SourcePackage,
+    file_name: &str,
+    file_path: &str,
+    project: &TargetProject,
+) -> Result<(), String> {
+    let package = package.clone();
+    let file_name = file_name.to_string();
+    let file_path = file_path.to_string();
+    let project = project.clone();
+    let spawn_result = tokio::task::spawn_blocking(move || {
+        match synchronous_upload_target_package_file(&package, &file_name, &file_path, &project) {
+            Ok(x) => Ok(x),
+            Err(err) => Err(format!(
+                "Failed to upload {} of {}\n{}.",
+                file_name,
+                package.key(),
+                err
+            )),
+        }
+    })
+    .await;
+    spawn_result.map_err(|_| "Spawn blocking failed!".to_string())?
+}
+
+// The blocking client streams the file from disk, and unlike the shared
+// client it does not need to clone the body for retries.
+fn synchronous_upload_target_package_file(
+    package: &SourcePackage,
+    file_name: &str,
+    file_path: &str,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Uploading {} of {} to {}...",
+        file_name,
+        package.key(),
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/packages/{}",
+        *TARGET_GITLAB_URL,
+        project.id,
+        package_file_path(package, file_name)
+    );
+    let file = std::fs::File::open(file_path)?;
+    let length = file.metadata()?.len();
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(900))
+        .build()?;
+    client
+        .put(url)
+        .body(reqwest::blocking::Body::sized(file, length))
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()?
+        .error_for_status()?;
+    Ok(())
+}
+
+// Publishing always moves the given dist-tags, so versions that no tag points
+// to are published under a temporary tag that is removed afterwards.
+const TEMPORARY_NPM_DIST_TAG: &str = "gitlab-migrator";
+
+pub async fn publish_target_npm_package(
+    package: &SourcePackage,
+    version_metadata: serde_json::Value,
+    dist_tags: &[String],
+    file_name: &str,
+    content: Vec<u8>,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!("Publishing {} to {}...", package.key(), project.key());
+    let version = package
+        .version
+        .as_ref()
+        .ok_or("Missing npm package version!")?;
+    let mut version_metadata = version_metadata;
+    version_metadata["dist"]["tarball"] = serde_json::Value::String(format!(
+        "{}/projects/{}/packages/npm/{}/-/{}",
+        *TARGET_GITLAB_URL, project.id, package.name, file_name
+    ));
+    let tags: Vec<_> = if dist_tags.is_empty() {
+        vec![TEMPORARY_NPM_DIST_TAG.to_string()]
+    } else {
+        dist_tags.to_vec()
+    };
+    let tags: serde_json::Map<_, _> = tags
+        .into_iter()
+        .map(|tag| (tag, serde_json::Value::String(version.to_string())))
+        .collect();
+    let body = serde_json::json!({
+        "name": package.name,
+        "versions": { version: version_metadata },
+        "dist-tags": tags,
+        "_attachments": {
+            file_name: {
+                "content_type": "application/octet-stream",
+                "data": base64::encode(&content),
+                "length": content.len(),
+            }
+        },
+    });
+    let url = format!(
+        "{}/projects/{}/packages/npm/{}",
+        *TARGET_GITLAB_URL,
+        project.id,
+        package.name.replace('/', "%2f")
+    );
+    http::CLIENT
+        .put(url)
+        .json(&body)
+        .bearer_auth(&*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?;
+
+    if dist_tags.is_empty() {
+        let url = format!(
+            "{}/projects/{}/packages/npm/-/package/{}/dist-tags/{}",
+            *TARGET_GITLAB_URL, project.id, package.name, TEMPORARY_NPM_DIST_TAG
+        );
+        http::CLIENT
+            .delete(url)
+            .bearer_auth(&*TARGET_GITLAB_TOKEN)
+            .send()
+            .await?
+            .error_for_status()?;
+    }
+    Ok(())
+}
+
+fn package_file_path(package: &SourcePackage, file_name: &str) -> String {
+    match (package.package_type.as_str(), &package.version) {
+        ("npm", _) => format!("npm/{}/-/{}", package.name, file_name),
+        (_, Some(version)) => format!(
+            "{}/{}/{}/{}",
+            package.package_type, package.name, version, file_name
+        ),
+        (_, None) => format!("{}/{}/{}", package.package_type, package.name, file_name),
+    }
+}
+
+pub async fn create_target_impersonation_token(
+    user: &TargetUser,
+) -> Result<TargetAccessToken, Box<dyn Error>> {
+    let url = format!(
+        "{}/users/{}/impersonation_tokens",
+        *TARGET_GITLAB_URL, user.id
+    );
+    let expires_at = chrono::Utc::today() + chrono::Duration::days(2);
+    let form = [
+        ("name", "gitlab-migrator".to_string()),
+        ("scopes[]", "api".to_string()),
+        ("expires_at", expires_at.format("%Y-%m-%d").to_string()),
+    ];
+    let payload = http::CLIENT
+        .post(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let token: TargetAccessToken = serde_json::from_str(&payload)?;
+    Ok(token)
+}
+
+pub async fn revoke_target_impersonation_token(
+    user: &TargetUser,
+    token: &TargetAccessToken,
+) -> Result<(), Box<dyn Error>> {
+    let url = format!(
+        "{}/users/{}/impersonation_tokens/{}",
+        *TARGET_GITLAB_URL, user.id, token.id
+    );
+    http::CLIENT
+        .delete(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+pub async fn fetch_all_target_project_snippets(
+    project: &TargetProject,
+) -> Result<Vec<TargetSnippet>, Box<dyn Error>> {
+    let mut all_snippets = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut snippets = fetch_target_project_snippets(project, latest_page).await?;
+        latest_len = snippets.len();
+        latest_page += 1;
+        all_snippets.append(&mut snippets);
+    }
+    Ok(all_snippets)
+}
+
+async fn fetch_target_project_snippets(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetSnippet>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/snippets", *TARGET_GITLAB_URL, project.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let snippets: Vec<TargetSnippet> = serde_json::from_str(&payload)?;
+    Ok(snippets)
+}
+
+pub async fn create_target_project_snippet(
+    snippet: &SourceSnippet,
+    project: &TargetProject,
+    token: &TargetAccessToken,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating snippet '{}' in {}...",
+        snippet.title,
+        project.key()
+    );
+    let url = format!("{}/projects/{}/snippets", *TARGET_GITLAB_URL, project.id);
+    let result = http::CLIENT
+        .post(url)
+        .json(&snippet_body(snippet))
+        .header("PRIVATE-TOKEN", token.token.as_deref().unwrap_or_default())
+        .send()
+        .await?
+        .error_for_status();
+    if let Err(err) = result {
+        println!("Error: {}", err);
+        println!(
+            "Context: \n{:?}\nin\n{}\n__________",
+            snippet.title,
+            project.key()
+        );
+    }
+    Ok(())
+}
+
+// The snippets API only lists the snippets of the token's user.
+pub async fn fetch_all_target_personal_snippets(
+    token: &TargetAccessToken,
+) -> Result<Vec<TargetSnippet>, Box<dyn Error>> {
+    let mut all_snippets = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut snippets = fetch_target_personal_snippets(token, latest_page).await?;
+        latest_len = snippets.len();
+        latest_page += 1;
+        all_snippets.append(&mut snippets);
+    }
+    Ok(all_snippets)
+}
+
+async fn fetch_target_personal_snippets(
+    token: &TargetAccessToken,
+    page: u32,
+) -> Result<Vec<TargetSnippet>, Box<dyn Error>> {
+    let url = format!("{}/snippets", *TARGET_GITLAB_URL);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", token.token.as_deref().unwrap_or_default())
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let snippets: Vec<TargetSnippet> = serde_json::from_str(&payload)?;
+    Ok(snippets)
+}
+
+pub async fn create_target_personal_snippet(
+    snippet: &SourceSnippet,
+    user: &TargetUser,
+    token: &TargetAccessToken,
+) -> Result<(), Box<dyn Error>> {
+    println!("Creating snippet '{}' for {}...", snippet.title, user.key());
+    let url = format!("{}/snippets", *TARGET_GITLAB_URL);
+    let result = http::CLIENT
+        .post(url)
+        .json(&snippet_body(snippet))
+        .header("PRIVATE-TOKEN", token.token.as_deref().unwrap_or_default())
+        .send()
+        .await?
+        .error_for_status();
+    if let Err(err) = result {
+        println!("Error: {}", err);
+        println!(
+            "Context: \n{:?}\nfor\n{}\n__________",
+            snippet.title,
+            user.key()
+        );
+    }
+    Ok(())
+}
+
+fn snippet_body(snippet: &SourceSnippet) -> serde_json::Value {
+    let files: Vec<_> = snippet
+        .files
+        .iter()
+        .map(|file| serde_json::json!({ "file_path": file.path, "content": file.content }))
+        .collect();
+    serde_json::json!({
+        "title": snippet.title,
+        "description": snippet.description,
+        "visibility": snippet.visibility,
+        "files": files,
+    })
+}
+
+pub async fn fetch_target_project_statistics(
+    project: &TargetProject,
+) -> Result<ProjectStatistics, Box<dyn Error>> {
+    let url = format!("{}/projects/{}", *TARGET_GITLAB_URL, project.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("statistics", "true")])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let project: ProjectWithStatistics = serde_json::from_str(&payload)?;
+    let statistics = project
+        .statistics
+        .ok_or("Missing statistics for target project!")?;
+    Ok(statistics)
+}
+
+pub async fn fetch_target_project_settings(
+    project: &TargetProject,
+) -> Result<ProjectSettings, Box<dyn Error>> {
+    let url = format!("{}/projects/{}", *TARGET_GITLAB_URL, project.id);
+    let payload = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let settings: ProjectSettings = serde_json::from_str(&payload)?;
+    Ok(settings)
+}
+
+pub async fn update_target_project_settings(
+    project: &TargetProject,
+    changes: &serde_json::Map<String, serde_json::Value>,
+) -> Result<bool, Box<dyn Error>> {
+    println!("Updating settings of {}...", project.key());
+    let url = format!("{}/projects/{}", *TARGET_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .put(url)
+        .json(changes)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    let status = response.status();
+    if !status.is_success() {
+        println!("Error: {}", status);
+        println!(
+            "Context: \n{:?}\nin\n{}\n__________",
+            response.text().await?,
+            project.key()
+        );
+    }
+    Ok(status.is_success())
+}
+
+pub async fn fetch_all_target_repository_refs(
+    project: &TargetProject,
+    kind: &str,
+) -> Result<Vec<RepositoryRef>, Box<dyn Error>> {
+    let mut all_refs = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut refs = fetch_target_repository_refs(project, kind, latest_page).await?;
+        latest_len = refs.len();
+        latest_page += 1;
+        all_refs.append(&mut refs);
+    }
+    Ok(all_refs)
+}
+
+async fn fetch_target_repository_refs(
+    project: &TargetProject,
+    kind: &str,
+    page: u32,
+) -> Result<Vec<RepositoryRef>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/repository/{}",
+        *TARGET_GITLAB_URL, project.id, kind
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let refs: Vec<RepositoryRef> = serde_json::from_str(&payload)?;
+    Ok(refs)
+}
+
+pub async fn create_target_ci_variable(
+    variable: SourceVariable,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!("Creating variable {} in {}...", variable.key, project.key());
+    let url = format!("{}/projects/{}/variables", *TARGET_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .post(url)
+        .form(&[
+            ("key", variable.key),
+            ("value", variable.value),
+            ("variable_type", variable.variable_type),
+            ("protected", variable.protected.to_string()),
+            ("masked", variable.masked.to_string()),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_boards(
+    membership: &TargetMembership,
+) -> Result<Vec<TargetBoard>, Box<dyn Error>> {
+    let mut all_boards = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut boards = fetch_target_boards(membership, latest_page).await?;
+        latest_len = boards.len();
+        latest_page += 1;
+        all_boards.append(&mut boards);
+    }
+    Ok(all_boards)
+}
+
+async fn fetch_target_boards(
+    membership: &TargetMembership,
+    page: u32,
+) -> Result<Vec<TargetBoard>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/boards",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let boards: Vec<TargetBoard> = serde_json::from_str(&payload)?;
+    Ok(boards)
+}
+
+pub async fn create_target_board(
+    board: &SourceBoard,
+    membership: &TargetMembership,
+) -> Result<Option<TargetBoard>, Box<dyn Error>> {
+    println!("Creating board '{}' in {}...", board.name, membership.key());
+    let url = format!(
+        "{}/{}/{}/boards",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let result = http::CLIENT
+        .post(url)
+        .form(&[("name", &board.name)])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status();
+    match result {
+        Ok(response) => Ok(Some(serde_json::from_str(&response.text().await?)?)),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{:?}\nin\n{}\n__________",
+                board.name,
+                membership.key()
+            );
+            Ok(None)
+        }
+    }
+}
+
+pub async fn update_target_board_scope(
+    target_board: &TargetBoard,
+    board: &SourceBoard,
+    assignee_id: Option<u32>,
+    milestone_id: Option<u32>,
+    membership: &TargetMembership,
+) -> Result<bool, Box<dyn Error>> {
+    println!(
+        "Updating the scope of board '{}' in {}...",
+        board.name,
+        membership.key()
+    );
+    let url = format!(
+        "{}/{}/{}/boards/{}",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id(),
+        target_board.id
+    );
+    let labels: Vec<_> = board.labels.iter().map(|x| x.name.to_owned()).collect();
+    let form = vec![
+        ("hide_backlog_list", board.hide_backlog_list.to_string()),
+        ("hide_closed_list", board.hide_closed_list.to_string()),
+        ("labels", labels.join(",")),
+        (
+            "assignee_id",
+            assignee_id.map(|x| x.to_string()).unwrap_or_default(),
+        ),
+        (
+            "milestone_id",
+            milestone_id.map(|x| x.to_string()).unwrap_or_default(),
+        ),
+        (
+            "weight",
+            board.weight.map(|x| x.to_string()).unwrap_or_default(),
+        ),
+    ];
+    let response = http::CLIENT
+        .put(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    match response.error_for_status_ref() {
+        Ok(_) => Ok(true),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{}\nin\n{}\n__________",
+                response.text().await?,
+                membership.key()
+            );
+            Ok(false)
+        }
+    }
+}
+
+pub async fn create_target_board_list(
+    board: &TargetBoard,
+    field: (&str, u32),
+    membership: &TargetMembership,
+) -> Result<Option<TargetBoardList>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/boards/{}/lists",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id(),
+        board.id
+    );
+    let (field, id) = field;
+    let result = http::CLIENT
+        .post(url)
+        .form(&[(field, id.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status();
+    match result {
+        Ok(response) => {
+            let payload = response.text().await?;
+            let created: TargetBoardList = serde_json::from_str(&payload)?;
+            Ok(Some(created))
+        }
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{} {}\nin\n{}\n__________",
+                field,
+                id,
+                membership.key()
+            );
+            Ok(None)
+        }
+    }
+}
+
+pub async fn move_target_board_list(
+    board: &TargetBoard,
+    list: &TargetBoardList,
+    position: u32,
+    membership: &TargetMembership,
+) -> Result<(), Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/boards/{}/lists/{}",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id(),
+        board.id,
+        list.id
+    );
+    let response = http::CLIENT
+        .put(url)
+        .form(&[("position", position.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_labels(
+    membership: &TargetMembership,
+) -> Result<Vec<TargetLabel>, Box<dyn Error>> {
+    let mut all_labels = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut labels = fetch_target_labels(membership, latest_page).await?;
+        latest_len = labels.len();
+        latest_page += 1;
+        all_labels.append(&mut labels);
+    }
+    Ok(all_labels)
+}
+
+async fn fetch_target_labels(
+    membership: &TargetMembership,
+    page: u32,
+) -> Result<Vec<TargetLabel>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/labels",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("include_ancestor_groups", "true"),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let labels: Vec<TargetLabel> = serde_json::from_str(&payload)?;
+    Ok(labels)
+}
+
+pub async fn fetch_all_target_milestones(
+    membership: &TargetMembership,
+) -> Result<Vec<TargetMilestone>, Box<dyn Error>> {
+    let mut all_milestones = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut milestones = fetch_target_milestones(membership, latest_page).await?;
+        latest_len = milestones.len();
+        latest_page += 1;
+        all_milestones.append(&mut milestones);
+    }
+    Ok(all_milestones)
+}
+
+async fn fetch_target_milestones(
+    membership: &TargetMembership,
+    page: u32,
+) -> Result<Vec<TargetMilestone>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/milestones",
+        *TARGET_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("include_ancestors", "true"),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let milestones: Vec<TargetMilestone> = serde_json::from_str(&payload)?;
+    Ok(milestones)
+}
+
+// The integrations list is not paginated, so one request returns all of them.
+pub async fn fetch_target_integrations(
+    project: &TargetProject,
+) -> Result<Vec<TargetIntegration>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/integrations",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let integrations: Vec<TargetIntegration> = serde_json::from_str(&payload)?;
+    Ok(integrations)
+}
+
+pub async fn update_target_integration(
+    slug: &str,
+    settings: &HashMap<String, serde_json::Value>,
+    project: &TargetProject,
+) -> Result<bool, Box<dyn Error>> {
+    println!("Setting up integration {} in {}...", slug, project.key());
+    let url = format!(
+        "{}/projects/{}/integrations/{}",
+        *TARGET_GITLAB_URL, project.id, slug
+    );
+    let response = http::CLIENT
+        .put(url)
+        .json(settings)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    match response.error_for_status_ref() {
+        Ok(_) => Ok(true),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{}\nin\n{}\n__________",
+                response.text().await?,
+                project.key()
+            );
+            Ok(false)
+        }
+    }
+}
+
+pub async fn create_target_runner(
+    runner: &SourceRunner,
+    membership: &TargetMembership,
+) -> Result<Option<TargetRunner>, Box<dyn Error>> {
+    let description = runner.description.to_owned().unwrap_or_default();
+    println!(
+        "Creating runner '{}' in {}...",
+        description,
+        membership.key()
+    );
+    let url = format!("{}/user/runners", *TARGET_GITLAB_URL);
+    let mut form = vec![
+        ("runner_type", runner.runner_type.to_owned()),
+        ("description", description),
+        ("tag_list", runner.tag_list.join(",")),
+        ("run_untagged", runner.run_untagged.to_string()),
+        ("locked", runner.locked.to_string()),
+        ("access_level", runner.access_level.to_owned()),
+        ("paused", runner.paused.to_string()),
+    ];
+    match membership {
+        TargetMembership::Group(group) => form.push(("group_id", group.id.to_string())),
+        TargetMembership::Project(project) => form.push(("project_id", project.id.to_string())),
+    }
+    if let Some(maximum_timeout) = runner.maximum_timeout {
+        form.push(("maximum_timeout", maximum_timeout.to_string()));
+    }
+    let result = http::CLIENT
+        .post(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status();
+    match result {
+        Ok(response) => {
+            let payload = response.text().await?;
+            let created: TargetRunner = serde_json::from_str(&payload)?;
+            Ok(Some(created))
+        }
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{:?}\nin\n{}\n__________",
+                runner,
+                membership.key()
+            );
+            Ok(None)
+        }
+    }
+}
+
+pub async fn enable_target_project_runner(
+    runner_id: u32,
+    project: &TargetProject,
+) -> Result<(), Box<dyn Error>> {
+    println!("Enabling runner {} in {}...", runner_id, project.key());
+    let url = format!("{}/projects/{}/runners", *TARGET_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .post(url)
+        .form(&[("runner_id", runner_id.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub fn target_web_url() -> String {
+    web_url(&TARGET_GITLAB_URL)
+}
+
+pub fn source_host() -> String {
+    web_url(&SOURCE_GITLAB_URL)
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .to_string()
+}
+
+pub async fn fetch_all_target_triggers(
+    project: &TargetProject,
+) -> Result<Vec<TargetTrigger>, Box<dyn Error>> {
+    let mut all_triggers = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut triggers = fetch_target_triggers(project, latest_page).await?;
+        latest_len = triggers.len();
+        latest_page += 1;
+        all_triggers.append(&mut triggers);
+    }
+    Ok(all_triggers)
+}
+
+async fn fetch_target_triggers(
+    project: &TargetProject,
+    page: u32,
+) -> Result<Vec<TargetTrigger>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/triggers", *TARGET_GITLAB_URL, project.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let triggers: Vec<TargetTrigger> = serde_json::from_str(&payload)?;
+    Ok(triggers)
+}
+
+pub async fn create_target_trigger(
+    trigger: &SourceTrigger,
+    project: &TargetProject,
+    token: &str,
+) -> Result<Option<TargetTrigger>, Box<dyn Error>> {
+    println!(
+        "Creating trigger '{}' in {}...",
+        trigger.description,
+        project.key()
+    );
+    let url = format!("{}/projects/{}/triggers", *TARGET_GITLAB_URL, project.id);
+    let result = http::CLIENT
+        .post(url)
+        .form(&[("description", &trigger.description)])
+        .header("PRIVATE-TOKEN", token)
+        .send()
+        .await?
+        .error_for_status();
+    match result {
+        Ok(response) => {
+            let payload = response.text().await?;
+            let created: TargetTrigger = serde_json::from_str(&payload)?;
+            Ok(Some(created))
+        }
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{:?}\nin\n{}\n__________",
+                trigger.description,
+                project.key()
+            );
+            Ok(None)
+        }
+    }
+}
+
+pub async fn create_target_pipeline_schedule(
+    schedule: SourcePipelineSchedule,
+    project: &TargetProject,
+    token: &str,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Creating pipeline schedule '{}' in {}...",
+        schedule.description,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/pipeline_schedules",
+        *TARGET_GITLAB_URL, project.id
+    );
+    let result = http::CLIENT
+        .post(url)
+        .form(&[
+            ("description", schedule.description),
+            ("ref", schedule.ref_),
+            ("cron", schedule.cron),
+            ("cron_timezone", schedule.cron_timezone),
+            ("active", schedule.active.to_string()),
+        ])
+        .header("PRIVATE-TOKEN", token)
+        .send()
+        .await?
+        .error_for_status();
+    match result {
+        Ok(response) => {
+            let payload = response.text().await?;
+            let created: TargetPipelineSchedule = serde_json::from_str(&payload)?;
+            println!(
+                "Created pipeline schedule '{}' in {}!",
+                created.description,
+                project.key()
+            );
+            let variables = schedule.variables.unwrap_or_default();
+            create_target_pipeline_schedule_variables(variables, &created, project, token).await?;
+        }
+        Err(err) => println!("{:#?}", err),
+    }
+    Ok(())
+}
+
+// Schedules created by the project import belong to the importing user.
+pub async fn take_ownership_of_target_pipeline_schedule(
+    schedule: SourcePipelineSchedule,
+    existing: &TargetPipelineSchedule,
+    project: &TargetProject,
+    token: &str,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Taking ownership of pipeline schedule '{}' in {}...",
+        existing.description,
+        project.key()
+    );
+    let url = format!(
+        "{}/projects/{}/pipeline_schedules/{}/take_ownership",
+        *TARGET_GITLAB_URL, project.id, existing.id
+    );
+    let result = http::CLIENT
+        .post(url)
+        .header("PRIVATE-TOKEN", token)
+        .send()
+        .await?
+        .error_for_status();
+    match result {
+        Ok(_) => {
+            let existing = fetch_target_pipeline_schedule(existing, project, token).await?;
+            let variables = schedule.variables.unwrap_or_default();
+            create_target_pipeline_schedule_variables(variables, &existing, project, token).await?;
+        }
+        Err(err) => println!("{:#?}", err),
+    }
+    Ok(())
+}
+
+async fn fetch_target_pipeline_schedule(
+    schedule: &TargetPipelineSchedule,
+    project: &TargetProject,
+    token: &str,
+) -> Result<TargetPipelineSchedule, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/pipeline_schedules/{}",
+        *TARGET_GITLAB_URL, project.id, schedule.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", token)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let schedule: TargetPipelineSchedule = serde_json::from_str(&payload)?;
+    Ok(schedule)
+}
+
+async fn create_target_pipeline_schedule_variables(
+    variables: Vec<SourcePipelineVariable>,
+    schedule: &TargetPipelineSchedule,
+    project: &TargetProject,
+    token: &str,
+) -> Result<(), Box<dyn Error>> {
+    for variable in variables {
+        let existing = schedule.variables.iter().find(|x| x.key == variable.key);
+        let url = format!(
+            "{}/projects/{}/pipeline_schedules/{}/variables",
+            *TARGET_GITLAB_URL, project.id, schedule.id
+        );
+        let request = match existing {
+            Some(x) if x.value == variable.value && x.variable_type == variable.variable_type => {
+                continue
+            }
+            Some(_) => http::CLIENT.put(format!("{}/{}", url, variable.key)),
+            None => http::CLIENT.post(url),
+        };
+        let response = request
+            .form(&[
+                ("key", variable.key.to_owned()),
+                ("value", variable.value),
+                ("variable_type", variable.variable_type),
+            ])
+            .header("PRIVATE-TOKEN", token)
+            .send()
+            .await?;
+        if let Err(err) = response.error_for_status() {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{}\nin\n{}\n__________",
+                variable.key, schedule.description
+            );
+        }
+    }
+    Ok(())
+}
+
+pub async fn reassign_target_issue(
+    issue: SourceIssue,
+    project: &TargetProject,
+    assignees: Vec<TargetUser>,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Reassigning issue\n{:?}\nin project\n{:?}\nto\n{:?}\n__________",
+        issue, project, assignees
+    );
+    let url = format!(
+        "{}/projects/{}/issues/{}",
+        *TARGET_GITLAB_URL, project.id, issue.iid
+    );
+    let form: Vec<_> = assignees
+        .iter()
+        .map(|assignee| ("assignee_ids[]", assignee.id))
+        .collect();
+    let response = http::CLIENT
+        .put(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("Error: {}", err);
+        println!(
+            "Context: \n{:?}\nin project\n{:?}\nto\n{:?}\n__________",
+            issue, project, assignees
+        )
+    }
+    Ok(())
+}
+
+pub async fn reassign_target_merge_request(
+    merge_request: SourceMergeRequest,
+    project: &TargetProject,
+    assignees: Vec<TargetUser>,
+    reviewers: Vec<TargetUser>,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Reassigning merge request\n{:?}\nin project\n{:?}\nto\n{:?}\nwith reviewers\n{:?}\n__________",
+        merge_request, project, assignees, reviewers
+    );
+    let url = format!(
+        "{}/projects/{}/merge_requests/{}",
+        *TARGET_GITLAB_URL, project.id, merge_request.iid
+    );
+    let form: Vec<_> = assignees
+        .iter()
+        .map(|assignee| ("assignee_ids[]", assignee.id))
+        .chain(
+            reviewers
+                .iter()
+                .map(|reviewer| ("reviewer_ids[]", reviewer.id)),
+        )
+        .collect();
+    let response = http::CLIENT
+        .put(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("Error: {}", err);
+        println!(
+            "Context: \n{:?}\nin project\n{:?}\nto\n{:?}\nwith reviewers\n{:?}\n__________",
+            merge_request, project, assignees, reviewers
+        )
+    }
+    Ok(())
+}
+
+pub async fn add_target_project_member_to_project(
+    project: TargetProject,
+    user: TargetUser,
+    member: SourceMember,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Adding user {:?} to project {:?} from access level {:?}...",
+        user, project, member.access_level
+    );
+    let url = format!("{}/projects/{}/members", *TARGET_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .post(url)
+        .form(&[
+            ("user_id", &user.id.to_string()),
+            ("access_level", &member.access_level.to_string()),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn add_target_project_member_to_group(
+    group: TargetGroup,
+    user: TargetUser,
+    member: SourceMember,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Adding user {:?} to group {:?} from access level {:?}...",
+        user, group, member.access_level
+    );
+    let url = format!("{}/groups/{}/members", *TARGET_GITLAB_URL, group.id);
+    let response = http::CLIENT
+        .post(url)
+        .form(&[
+            ("user_id", &user.id.to_string()),
+            ("access_level", &member.access_level.to_string()),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_all_target_groups() -> Result<Vec<TargetGroup>, Box<dyn Error>> {
+    let mut all_groups = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut groups = fetch_target_groups(latest_page).await?;
+        latest_len = groups.len();
+        latest_page += 1;
+        all_groups.append(&mut groups);
+    }
+    Ok(all_groups)
+}
+
+async fn fetch_target_groups(page: u32) -> Result<Vec<TargetGroup>, Box<dyn Error>> {
+    let url = format!("{}/groups/", *TARGET_GITLAB_URL);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    let payload = &response.text().await?;
+    let groups: Vec<TargetGroup> = serde_json::from_str(payload)?;
+    Ok(groups)
+}
+
+pub async fn delete_target_project(project: TargetProject) -> Result<(), Box<dyn Error>> {
+    println!("Deleting project {:?}...", project);
+    let url = format!("{}/projects/{}", *TARGET_GITLAB_URL, project.id);
+    http::CLIENT
+        .delete(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+pub async fn fetch_all_target_projects() -> Result<Vec<TargetProject>, Box<dyn Error>> {
+    let mut all_projects = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut projects = fetch_target_projects(latest_page).await?;
+        latest_len = projects.len();
+        latest_page += 1;
+        all_projects.append(&mut projects);
+    }
+    Ok(all_projects)
+}
+
+pub async fn fetch_target_projects(page: u32) -> Result<Vec<TargetProject>, Box<dyn Error>> {
+    let url = format!("{}/projects", *TARGET_GITLAB_URL);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?;
+    let payload = &response.text().await?;
+    let projects: Vec<TargetProject> = serde_json::from_str(payload)?;
+    Ok(projects)
+}
+
+pub async fn import_target_project(project: SourceProject) -> Result<(), String> {
+    let spawn_result =
+        tokio::task::spawn_blocking(move || match synchronous_import_target_project(project) {
+            Ok(x) => Ok(x),
+            Err(err) => {
+                println!("{:#?}", err);
+                Err("Failed to import target project!".to_owned())
+            }
+        })
+        .await;
+    spawn_result.map_err(|_| "Spawn blocking failed!".to_string())?
+}
+
+pub fn synchronous_import_target_project(project: SourceProject) -> Result<(), Box<dyn Error>> {
+    println!("Importing project {:?}...", project);
+    let gz_path = format!("cache/projects/{}.gz", project.id);
+    let namespace = parse_namespace(&project);
+    let form = reqwest::blocking::multipart::Form::new()
+        .text("namespace", namespace)
+        .text("name", project.name)
+        .text("path", project.path)
+        .file("file", gz_path)?;
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(900))
+        .build()?;
+    let url = format!("{}/projects/import", *TARGET_GITLAB_URL);
+    client
+        .post(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .multipart(form)
+        .send()?
+        .error_for_status()?;
+    Ok(())
+}
+
+fn parse_namespace(project: &SourceProject) -> String {
+    let mut path = project.path_with_namespace.split('/').rev();
+    path.next();
+    path.rev().fold(String::new(), |x, y| {
+        if x.is_empty() {
+            y.to_string()
+        } else {
+            x + "/" + y
+        }
+    })
+}
+
+pub async fn fetch_all_target_users() -> Result<Vec<TargetUser>, Box<dyn Error>> {
+    let mut all_users = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut users = fetch_target_users(latest_page).await?;
+        latest_len = users.len();
+        latest_page += 1;
+        all_users.append(&mut users);
+    }
+    Ok(all_users)
+}
+
+pub async fn fetch_target_users(page: u32) -> Result<Vec<TargetUser>, Box<dyn Error>> {
+    let url = format!("{}/users", *TARGET_GITLAB_URL);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("with_custom_attributes", "true"),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?;
+    let payload = &response.text().await?;
+    let users: Vec<TargetUser> = serde_json::from_str(payload)?;
+    Ok(users)
+}
+
+pub async fn fetch_target_ssh_keys(user: &TargetUser) -> Result<Vec<TargetSshKey>, Box<dyn Error>> {
+    let url = format!("{}/users/{}/keys", *TARGET_GITLAB_URL, user.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100")])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let keys: Vec<TargetSshKey> = serde_json::from_str(&payload)?;
+    Ok(keys)
+}
+
+pub async fn create_target_ssh_key(
+    key: &SourceSshKey,
+    user: &TargetUser,
+) -> Result<(), Box<dyn Error>> {
+    println!("Adding SSH key '{}' to {}...", key.title, user.key());
+    let url = format!("{}/users/{}/keys", *TARGET_GITLAB_URL, user.id);
+    let mut form = vec![("title", key.title.to_owned()), ("key", key.key.to_owned())];
+    if let Some(expires_at) = &key.expires_at {
+        form.push(("expires_at", expires_at.to_owned()));
+    }
+    if let Some(usage_type) = &key.usage_type {
+        form.push(("usage_type", usage_type.to_owned()));
+    }
+    let response = http::CLIENT
+        .post(url)
+        .form(&form)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn fetch_target_gpg_keys(user: &TargetUser) -> Result<Vec<TargetGpgKey>, Box<dyn Error>> {
+    let url = format!("{}/users/{}/gpg_keys", *TARGET_GITLAB_URL, user.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100")])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let keys: Vec<TargetGpgKey> = serde_json::from_str(&payload)?;
+    Ok(keys)
+}
+
+pub async fn create_target_gpg_key(
+    key: &SourceGpgKey,
+    user: &TargetUser,
+) -> Result<(), Box<dyn Error>> {
+    println!("Adding GPG key to {}...", user.key());
+    let url = format!("{}/users/{}/gpg_keys", *TARGET_GITLAB_URL, user.id);
+    let response = http::CLIENT
+        .post(url)
+        .form(&[("key", &key.key)])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn update_target_user_identity(
+    user: &TargetUser,
+    identity: &UserIdentity,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Linking user {} to {} identity {}...",
+        user.username, identity.provider, identity.extern_uid
+    );
+    let url = format!("{}/users/{}", *TARGET_GITLAB_URL, user.id);
+    let response = http::CLIENT
+        .put(url)
+        .form(&[
+            ("provider", &identity.provider),
+            ("extern_uid", &identity.extern_uid),
+        ])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn update_target_user_external(
+    user: &TargetUser,
+    external: bool,
+) -> Result<(), Box<dyn Error>> {
+    println!("Setting external={} on user {}...", external, user.username);
+    let url = format!("{}/users/{}", *TARGET_GITLAB_URL, user.id);
+    let response = http::CLIENT
+        .put(url)
+        .form(&[("external", external.to_string())])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn update_target_user_state(
+    user: &TargetUser,
+    action: &str,
+) -> Result<bool, Box<dyn Error>> {
+    println!("Applying {} to user {}...", action, user.username);
+    let url = format!("{}/users/{}/{}", *TARGET_GITLAB_URL, user.id, action);
+    let response = http::CLIENT
+        .post(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    match response.error_for_status_ref() {
+        Ok(_) => Ok(true),
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \n{}\nin\n{}\n__________",
+                response.text().await?,
+                user.username
+            );
+            Ok(false)
+        }
+    }
+}
+
+pub async fn set_target_user_custom_attribute(
+    user: &TargetUser,
+    key: &str,
+    value: &str,
+) -> Result<(), Box<dyn Error>> {
+    println!(
+        "Setting custom attribute {}={} on user {}...",
+        key, value, user.username
+    );
+    let url = format!(
+        "{}/users/{}/custom_attributes/{}",
+        *TARGET_GITLAB_URL, user.id, key
+    );
+    let response = http::CLIENT
+        .put(url)
+        .form(&[("value", value)])
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if let Err(err) = response.error_for_status() {
+        println!("{}", err);
+    }
+    Ok(())
+}
+
+pub async fn delete_target_user(user: TargetUser) -> Result<(), Box<dyn Error>> {
+    println!("Deleting user {:?}...", user);
+    let url = format!("{}/users/{}", *TARGET_GITLAB_URL, user.id);
+    http::CLIENT
+        .delete(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+pub async fn create_target_user(
+    user: SourceUser,
+    email_mapping: &HashMap<String, String>,
+    profile: Option<SourceUserProfile>,
+    identity: Option<UserIdentity>,
+) -> Result<TargetUser, String> {
+    let user_str = format!("{:?}", user);
+    let email = match email_mapping.get(&user.username) {
+        Some(x) => x.to_string(),
+        None => format!("{}@test.com", user.username),
+    };
+    let email_str = email.to_string();
+    let spawn_result = tokio::task::spawn_blocking(move || {
+        match synchronous_create_target_user(user, email, profile, identity) {
+            Ok(x) => Ok(x),
+            Err(err) => Err(format!(
+                "Failed to create {}\n{}\n{}.",
+                user_str, email_str, err
+            )),
+        }
+    })
+    .await;
+    spawn_result.map_err(|_| "Spawn blocking failed!".to_string())?
+}
+
+pub fn synchronous_create_target_user(
+    user: SourceUser,
+    email: String,
+    profile: Option<SourceUserProfile>,
+    identity: Option<UserIdentity>,
+) -> Result<TargetUser, Box<dyn Error>> {
+    println!("Creating user {:?} with email {}...", user, email);
+    let avatar = synchronous_download_avatar(&user)?;
+    let client = reqwest::blocking::Client::new();
+    let url = format!("{}/users", *TARGET_GITLAB_URL);
+    let mut form = reqwest::blocking::multipart::Form::new()
+        .text("name", user.name)
+        .text("username", user.username)
+        .text("email", email)
+        .text("force_random_password", "true")
+        .text("reset_password", "true")
+        .text("skip_confirmation", "true")
+        .file("avatar", avatar)?;
+    if let Some(identity) = identity {
+        form = form
+            .text("provider", identity.provider)
+            .text("extern_uid", identity.extern_uid);
+    }
+    if let Some(profile) = &profile {
+        let texts = [
+            ("bio", &profile.bio),
+            ("job_title", &profile.job_title),
+            ("website_url", &profile.website_url),
+            ("location", &profile.location),
+        ];
+        for (name, value) in texts {
+            if let Some(value) = value.as_ref().filter(|x| !x.is_empty()) {
+                form = form.text(name, value.to_owned());
+            }
+        }
+        form = form.text("external", profile.external.to_string());
+        if let Some(is_admin) = profile.is_admin {
+            form = form.text("admin", is_admin.to_string());
+        }
+        if let Some(can_create_group) = profile.can_create_group {
+            form = form.text("can_create_group", can_create_group.to_string());
+        }
+    }
+
+    let response = client
+        .post(url)
+        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .multipart(form)
+        .send()?
+        .error_for_status()?;
+
+    let payload = response.text()?;
+    let member: TargetUser = serde_json::from_str(&payload)?;
+    Ok(member)
+}
+
+pub fn synchronous_download_avatar(user: &SourceUser) -> Result<String, Box<dyn Error>> {
+    println!("Downloading avatar for {}...", user.username);
+    let client = reqwest::blocking::Client::new();
+    let response = client.get(&user.avatar_url).send()?;
+    let dir_path = "cache/avatars";
+    std::fs::create_dir_all(dir_path)?;
+    let png_path = format!("{}/{}.png", dir_path, user.username);
+    let mut file = std::fs::File::create(&png_path)?;
+    let mut content = std::io::Cursor::new(response.bytes()?);
+    std::io::copy(&mut content, &mut file)?;
+    Ok(png_path)
+}
+
+pub async fn fetch_source_ci_variables(
+    project: &SourceProject,
+) -> Result<Vec<SourceVariable>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/variables", *SOURCE_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let variables: Vec<SourceVariable> = serde_json::from_str(payload)?;
+        Ok(variables)
+    } else {
+        Ok(vec![])
+    }
+}
+
+pub async fn fetch_all_source_boards(
+    membership: Membership,
+) -> Result<Vec<SourceBoard>, Box<dyn Error>> {
+    let mut all_boards = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut boards = fetch_source_boards(&membership, latest_page).await?;
+        latest_len = boards.len();
+        latest_page += 1;
+        all_boards.append(&mut boards);
+    }
+    Ok(all_boards)
+}
+
+async fn fetch_source_boards(
+    membership: &Membership,
+    page: u32,
+) -> Result<Vec<SourceBoard>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/boards",
+        *SOURCE_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let boards: Vec<SourceBoard> = serde_json::from_str(&payload)?;
+    Ok(boards)
+}
+
+pub async fn fetch_source_integrations(
+    project: &SourceProject,
+) -> Result<Vec<SourceIntegration>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/integrations",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let integrations: Vec<SourceIntegrationWithoutProperties> = serde_json::from_str(&payload)?;
+
+    let mut with_properties = vec![];
+    for integration in integrations.into_iter().filter(|x| x.active) {
+        let url = format!(
+            "{}/projects/{}/integrations/{}",
+            *SOURCE_GITLAB_URL, project.id, integration.slug
+        );
+        let payload = http::CLIENT
+            .get(url)
+            .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let integration: SourceIntegration = serde_json::from_str(&payload)?;
+        with_properties.push(integration);
+    }
+    Ok(with_properties)
+}
+
+pub async fn fetch_source_runners(
+    membership: Membership,
+) -> Result<Vec<SourceRunner>, Box<dyn Error>> {
+    let runners = fetch_all_source_runner_ids(&membership).await?;
+
+    let mut with_details = vec![];
+    for runner in runners {
+        let url = format!("{}/runners/{}", *SOURCE_GITLAB_URL, runner.id);
+        let payload = http::CLIENT
+            .get(url)
+            .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let runner: SourceRunner = serde_json::from_str(&payload)?;
+        // Group runners are also listed in every subgroup of their group.
+        let owned = match &membership {
+            Membership::Group(group) => runner.groups.iter().any(|x| x.id == group.id),
+            Membership::Project(_) => true,
+        };
+        if owned && !runner.is_shared {
+            with_details.push(runner);
+        }
+    }
+    Ok(with_details)
+}
+
+async fn fetch_all_source_runner_ids(
+    membership: &Membership,
+) -> Result<Vec<SourceRunnerWithoutDetails>, Box<dyn Error>> {
+    let mut all_runners = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut runners = fetch_source_runner_ids(membership, latest_page).await?;
+        latest_len = runners.len();
+        latest_page += 1;
+        all_runners.append(&mut runners);
+    }
+    Ok(all_runners)
+}
+
+async fn fetch_source_runner_ids(
+    membership: &Membership,
+    page: u32,
+) -> Result<Vec<SourceRunnerWithoutDetails>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/runners",
+        *SOURCE_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let runner_type = match membership {
+        Membership::Group(_) => "group_type",
+        Membership::Project(_) => "project_type",
+    };
+    let response = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("type", runner_type),
+        ])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Ok(vec![]);
+    }
+    let payload = &response.text().await?;
+    let runners: Vec<SourceRunnerWithoutDetails> = serde_json::from_str(payload)?;
+    Ok(runners)
+}
+
+pub async fn fetch_all_source_triggers(
+    project: &SourceProject,
+) -> Result<Vec<SourceTrigger>, Box<dyn Error>> {
+    let mut all_triggers = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut triggers = fetch_source_triggers(project, latest_page).await?;
+        latest_len = triggers.len();
+        latest_page += 1;
+        all_triggers.append(&mut triggers);
+    }
+    Ok(all_triggers)
+}
+
+async fn fetch_source_triggers(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceTrigger>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/triggers", *SOURCE_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let triggers: Vec<SourceTrigger> = serde_json::from_str(payload)?;
+        Ok(triggers)
+    } else {
+        Ok(vec![])
+    }
+}
+
+pub async fn fetch_all_source_access_tokens(
+    membership: Membership,
+) -> Result<Vec<SourceAccessToken>, Box<dyn Error>> {
+    let mut all_tokens = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut tokens = fetch_source_access_tokens(&membership, latest_page).await?;
+        latest_len = tokens.len();
+        latest_page += 1;
+        all_tokens.append(&mut tokens);
+    }
+    Ok(all_tokens)
+}
+
+async fn fetch_source_access_tokens(
+    membership: &Membership,
+    page: u32,
+) -> Result<Vec<SourceAccessToken>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/access_tokens",
+        *SOURCE_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let tokens: Vec<SourceAccessToken> = serde_json::from_str(payload)?;
+        Ok(tokens)
+    } else {
+        Ok(vec![])
+    }
+}
+
+pub async fn fetch_all_source_group_labels(
+    group: &SourceGroup,
+) -> Result<Vec<SourceLabel>, Box<dyn Error>> {
+    let mut all_labels = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut labels = fetch_source_group_labels(group, latest_page).await?;
+        latest_len = labels.len();
+        latest_page += 1;
+        all_labels.append(&mut labels);
+    }
+    Ok(all_labels)
+}
+
+async fn fetch_source_group_labels(
+    group: &SourceGroup,
+    page: u32,
+) -> Result<Vec<SourceLabel>, Box<dyn Error>> {
+    let url = format!("{}/groups/{}/labels", *SOURCE_GITLAB_URL, group.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("include_ancestor_groups", "false"),
+        ])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let labels: Vec<SourceLabel> = serde_json::from_str(&payload)?;
+    Ok(labels)
+}
+
+pub async fn fetch_all_source_group_milestones(
+    group: &SourceGroup,
+) -> Result<Vec<SourceMilestone>, Box<dyn Error>> {
+    let mut all_milestones = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut milestones = fetch_source_group_milestones(group, latest_page).await?;
+        latest_len = milestones.len();
+        latest_page += 1;
+        all_milestones.append(&mut milestones);
+    }
+    Ok(all_milestones)
+}
+
+async fn fetch_source_group_milestones(
+    group: &SourceGroup,
+    page: u32,
+) -> Result<Vec<SourceMilestone>, Box<dyn Error>> {
+    let url = format!("{}/groups/{}/milestones", *SOURCE_GITLAB_URL, group.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let milestones: Vec<SourceMilestone> = serde_json::from_str(&payload)?;
+    Ok(milestones)
+}
+
+pub async fn fetch_all_source_epics(
+    group: &SourceGroup,
+) -> Result<Vec<SourceEpic>, Box<dyn Error>> {
+    println!("Fetching all epics for {:?}...", group);
+    let mut all_epics = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut epics = fetch_source_epics(group, latest_page).await?;
+        latest_len = epics.len();
+        latest_page += 1;
+        all_epics.append(&mut epics);
+    }
+
+    for epic in all_epics.iter_mut() {
+        epic.issues = fetch_all_source_epic_issues(group, epic).await?;
+    }
+    Ok(all_epics)
+}
+
+async fn fetch_source_epics(
+    group: &SourceGroup,
+    page: u32,
+) -> Result<Vec<SourceEpic>, Box<dyn Error>> {
+    let url = format!("{}/groups/{}/epics", *SOURCE_GITLAB_URL, group.id);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("include_ancestor_groups", "false"),
+            ("include_descendant_groups", "false"),
+        ])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let epics: Vec<SourceEpic> = serde_json::from_str(payload)?;
+        Ok(epics)
+    } else {
+        Ok(vec![])
+    }
+}
+
+async fn fetch_all_source_epic_issues(
+    group: &SourceGroup,
+    epic: &SourceEpic,
+) -> Result<Vec<SourceEpicIssue>, Box<dyn Error>> {
+    let mut all_issues = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut issues = fetch_source_epic_issues(group, epic, latest_page).await?;
+        latest_len = issues.len();
+        latest_page += 1;
+        all_issues.append(&mut issues);
+    }
+    Ok(all_issues)
+}
+
+async fn fetch_source_epic_issues(
+    group: &SourceGroup,
+    epic: &SourceEpic,
+    page: u32,
+) -> Result<Vec<SourceEpicIssue>, Box<dyn Error>> {
+    let url = format!(
+        "{}/groups/{}/epics/{}/issues",
+        *SOURCE_GITLAB_URL, group.id, epic.iid
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let issues: Vec<SourceEpicIssue> = serde_json::from_str(&payload)?;
+    Ok(issues)
+}
+
+pub async fn fetch_all_source_badges(
+    membership: Membership,
+) -> Result<Vec<SourceBadge>, Box<dyn Error>> {
+    let mut all_badges = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut badges = fetch_source_badges(&membership, latest_page).await?;
+        latest_len = badges.len();
+        latest_page += 1;
+        all_badges.append(&mut badges);
+    }
+    let prefix = membership.url_prefix();
+    let badges = all_badges
+        .into_iter()
+        .filter(|badge| prefix == "groups" || badge.kind == "project")
+        .collect();
+    Ok(badges)
+}
+
+async fn fetch_source_badges(
+    membership: &Membership,
+    page: u32,
+) -> Result<Vec<SourceBadge>, Box<dyn Error>> {
+    let url = format!(
+        "{}/{}/{}/badges",
+        *SOURCE_GITLAB_URL,
+        membership.url_prefix(),
+        membership.id()
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let badges: Vec<SourceBadge> = serde_json::from_str(&payload)?;
+    Ok(badges)
+}
+
+pub async fn fetch_source_approvals(
+    project: &SourceProject,
+) -> Result<SourceProjectApprovals, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/approvals", *SOURCE_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    let settings: Option<SourceApprovalSettings> = if response.status().is_success() {
+        let payload = &response.text().await?;
+        Some(serde_json::from_str(payload)?)
+    } else {
+        None
+    };
+
+    let rules = fetch_all_source_approval_rules(project).await?;
+    Ok(SourceProjectApprovals { settings, rules })
+}
+
+async fn fetch_all_source_approval_rules(
+    project: &SourceProject,
+) -> Result<Vec<SourceApprovalRule>, Box<dyn Error>> {
+    let mut all_rules = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut rules = fetch_source_approval_rules(project, latest_page).await?;
+        latest_len = rules.len();
+        latest_page += 1;
+        all_rules.append(&mut rules);
+    }
+    Ok(all_rules)
+}
+
+async fn fetch_source_approval_rules(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceApprovalRule>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/approval_rules",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Ok(vec![]);
+    }
+    let payload = &response.text().await?;
+    let rules: Vec<SourceApprovalRule> = serde_json::from_str(payload)?;
+    Ok(rules)
+}
+
+pub async fn fetch_source_environments(
+    project: &SourceProject,
+) -> Result<SourceProjectEnvironments, Box<dyn Error>> {
+    let environments = fetch_all_source_project_environments(project).await?;
+    let protected_environments = fetch_all_source_protected_environments(project).await?;
+    let freeze_periods = fetch_all_source_freeze_periods(project).await?;
+    Ok(SourceProjectEnvironments {
+        environments,
+        protected_environments,
+        freeze_periods,
+    })
+}
+
+async fn fetch_all_source_freeze_periods(
+    project: &SourceProject,
+) -> Result<Vec<SourceFreezePeriod>, Box<dyn Error>> {
+    let mut all_freeze_periods = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut freeze_periods = fetch_source_freeze_periods(project, latest_page).await?;
+        latest_len = freeze_periods.len();
+        latest_page += 1;
+        all_freeze_periods.append(&mut freeze_periods);
+    }
+    Ok(all_freeze_periods)
+}
+
+async fn fetch_source_freeze_periods(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceFreezePeriod>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/freeze_periods",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Ok(vec![]);
+    }
+    let payload = &response.text().await?;
+    let freeze_periods: Vec<SourceFreezePeriod> = serde_json::from_str(payload)?;
+    Ok(freeze_periods)
+}
+
+async fn fetch_all_source_project_environments(
+    project: &SourceProject,
+) -> Result<Vec<SourceEnvironment>, Box<dyn Error>> {
+    let mut all_environments = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut environments = fetch_source_project_environments(project, latest_page).await?;
+        latest_len = environments.len();
+        latest_page += 1;
+        all_environments.append(&mut environments);
+    }
+    Ok(all_environments)
+}
+
+async fn fetch_source_project_environments(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceEnvironment>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/environments",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Ok(vec![]);
+    }
+    let payload = &response.text().await?;
+    let environments: Vec<SourceEnvironment> = serde_json::from_str(payload)?;
+    Ok(environments)
+}
+
+async fn fetch_all_source_protected_environments(
+    project: &SourceProject,
+) -> Result<Vec<SourceProtectedEnvironment>, Box<dyn Error>> {
+    let mut all_environments = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut environments = fetch_source_protected_environments(project, latest_page).await?;
+        latest_len = environments.len();
+        latest_page += 1;
+        all_environments.append(&mut environments);
+    }
+    Ok(all_environments)
+}
+
+async fn fetch_source_protected_environments(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceProtectedEnvironment>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/protected_environments",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Ok(vec![]);
+    }
+    let payload = &response.text().await?;
+    let environments: Vec<SourceProtectedEnvironment> = serde_json::from_str(payload)?;
+    Ok(environments)
+}
+
+pub async fn fetch_source_user_profile(
+    user: &SourceUser,
+) -> Result<Option<SourceUserProfile>, Box<dyn Error>> {
+    let url = format!("{}/users/{}", *SOURCE_GITLAB_URL, user.id);
+    let response = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let profile: SourceUserProfile = serde_json::from_str(payload)?;
+        Ok(Some(profile))
+    } else {
+        Ok(None)
+    }
+}
+
+pub async fn fetch_source_user_keys(user: &SourceUser) -> Result<SourceUserKeys, Box<dyn Error>> {
+    let url = format!("{}/users/{}/keys", *SOURCE_GITLAB_URL, user.id);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100")])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    let ssh_keys: Vec<SourceSshKey> = if response.status().is_success() {
+        serde_json::from_str(&response.text().await?)?
+    } else {
+        vec![]
+    };
+
+    let url = format!("{}/users/{}/gpg_keys", *SOURCE_GITLAB_URL, user.id);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100")])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    let gpg_keys: Vec<SourceGpgKey> = if response.status().is_success() {
+        serde_json::from_str(&response.text().await?)?
+    } else {
+        vec![]
+    };
+    Ok(SourceUserKeys { ssh_keys, gpg_keys })
+}
+
+pub async fn fetch_source_user(user_id: u32) -> Result<Option<SourceUser>, Box<dyn Error>> {
+    let url = format!("{}/users/{}", *SOURCE_GITLAB_URL, user_id);
+    let response = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let user: SourceUser = serde_json::from_str(payload)?;
+        Ok(Some(user))
+    } else {
+        Ok(None)
+    }
+}
+
+pub async fn fetch_all_source_registry_repositories(
+    project: &SourceProject,
+) -> Result<Vec<SourceRegistryRepository>, Box<dyn Error>> {
+    let mut all_repositories = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut repositories = fetch_source_registry_repositories(project, latest_page).await?;
+        latest_len = repositories.len();
+        latest_page += 1;
+        all_repositories.append(&mut repositories);
+    }
+    Ok(all_repositories)
+}
+
+async fn fetch_source_registry_repositories(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceRegistryRepository>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/registry/repositories",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let repositories: Vec<SourceRegistryRepository> = serde_json::from_str(payload)?;
+        Ok(repositories)
+    } else {
+        Ok(vec![])
+    }
+}
+
+pub async fn fetch_all_source_registry_tags(
+    project: &SourceProject,
+    repository: &SourceRegistryRepository,
+) -> Result<Vec<SourceRegistryTag>, Box<dyn Error>> {
+    let mut all_tags = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut tags = fetch_source_registry_tags(project, repository, latest_page).await?;
+        latest_len = tags.len();
+        latest_page += 1;
+        all_tags.append(&mut tags);
+    }
+    Ok(all_tags)
+}
+
+async fn fetch_source_registry_tags(
+    project: &SourceProject,
+    repository: &SourceRegistryRepository,
+    page: u32,
+) -> Result<Vec<SourceRegistryTag>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/registry/repositories/{}/tags",
+        *SOURCE_GITLAB_URL, project.id, repository.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let tags: Vec<SourceRegistryTag> = serde_json::from_str(&payload)?;
+    Ok(tags)
+}
+
+pub async fn fetch_all_source_packages(
+    project: &SourceProject,
+) -> Result<Vec<SourcePackage>, Box<dyn Error>> {
+    let mut all_packages = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut packages = fetch_source_packages(project, latest_page).await?;
+        latest_len = packages.len();
+        latest_page += 1;
+        all_packages.append(&mut packages);
+    }
+    Ok(all_packages)
+}
+
+async fn fetch_source_packages(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourcePackage>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/packages", *SOURCE_GITLAB_URL, project.id);
+    let response = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        let payload = &response.text().await?;
+        let packages: Vec<SourcePackage> = serde_json::from_str(payload)?;
+        Ok(packages)
+    } else {
+        Ok(vec![])
+    }
+}
+
+pub async fn fetch_all_source_package_files(
+    project: &SourceProject,
+    package: &SourcePackage,
+) -> Result<Vec<SourcePackageFile>, Box<dyn Error>> {
+    let mut all_files = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut files = fetch_source_package_files(project, package, latest_page).await?;
+        latest_len = files.len();
+        latest_page += 1;
+        all_files.append(&mut files);
+    }
+    Ok(all_files)
+}
+
+async fn fetch_source_package_files(
+    project: &SourceProject,
+    package: &SourcePackage,
+    page: u32,
+) -> Result<Vec<SourcePackageFile>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/packages/{}/package_files",
+        *SOURCE_GITLAB_URL, project.id, package.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let files: Vec<SourcePackageFile> = serde_json::from_str(&payload)?;
+    Ok(files)
+}
+
+pub async fn download_source_package_file(
+    project: &SourceProject,
+    package: &SourcePackage,
+    file_name: &str,
+) -> Result<Response, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/packages/{}",
+        *SOURCE_GITLAB_URL,
+        project.id,
+        package_file_path(package, file_name)
+    );
+    let request = http::CLIENT.get(url);
+    let request = match package.package_type.as_str() {
+        "npm" => request.bearer_auth(&*SOURCE_GITLAB_TOKEN),
+        _ => request.header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN),
+    };
+    let response = request.send().await?.error_for_status()?;
+    Ok(response)
+}
+
+pub async fn fetch_source_npm_version_metadata(
+    project: &SourceProject,
+    package: &SourcePackage,
+) -> Result<(serde_json::Value, Vec<String>), Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/packages/npm/{}",
+        *SOURCE_GITLAB_URL,
+        project.id,
+        package.name.replace('/', "%2f")
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .bearer_auth(&*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let metadata: serde_json::Value = serde_json::from_str(&payload)?;
+    let version = package
+        .version
+        .as_ref()
+        .ok_or("Missing npm package version!")?;
+    let version_metadata = metadata["versions"][version].clone();
+    if version_metadata.is_null() {
+        return Err(format!("Missing npm metadata for {}!", package.key()).into());
+    }
+    let dist_tags = metadata["dist-tags"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(_, x)| x.as_str() == Some(version))
+        .map(|(tag, _)| tag.to_string())
+        .collect();
+    Ok((version_metadata, dist_tags))
+}
+
+pub async fn fetch_source_project_snippets(
+    project: &SourceProject,
+) -> Result<Vec<SourceSnippet>, Box<dyn Error>> {
+    let mut all_snippets = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
     while latest_len == 100 || latest_page == 1 {
-        let mut users = fetch_target_users(latest_page).await?;
-        latest_len = users.len();
+        let mut snippets = fetch_source_project_snippets_page(project, latest_page).await?;
+        latest_len = snippets.len();
         latest_page += 1;
-        all_users.append(&mut users);
+        all_snippets.append(&mut snippets);
     }
-    Ok(all_users)
+    for snippet in all_snippets.iter_mut() {
+        let url = format!(
+            "{}/projects/{}/snippets/{}",
+            *SOURCE_GITLAB_URL, project.id, snippet.id
+        );
+        fetch_source_snippet_contents(&url, snippet).await?;
+    }
+    Ok(all_snippets)
 }
 
-pub async fn fetch_target_users(page: u32) -> Result<Vec<TargetUser>, Box<dyn Error>> {
-    let url = format!("{}/users", *TARGET_GITLAB_URL);
+async fn fetch_source_project_snippets_page(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceSnippet>, Box<dyn Error>> {
+    let url = format!("{}/projects/{}/snippets", *SOURCE_GITLAB_URL, project.id);
     let response = http::CLIENT
         .get(url)
         .query(&[("per_page", "100"), ("page", &page.to_string())])
-        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
         .send()
-        .await?
-        .error_for_status()?;
+        .await?;
+    if !response.status().is_success() {
+        return Ok(vec![]);
+    }
     let payload = &response.text().await?;
-    let users: Vec<TargetUser> = serde_json::from_str(payload)?;
-    Ok(users)
+    let snippets: Vec<SourceSnippet> = serde_json::from_str(payload)?;
+    Ok(snippets)
 }
 
-pub async fn delete_target_user(user: TargetUser) -> Result<(), Box<dyn Error>> {
-    println!("Deleting user {:?}...", user);
-    let url = format!("{}/users/{}", *TARGET_GITLAB_URL, user.id);
-    http::CLIENT
-        .delete(url)
-        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
+pub async fn fetch_source_token_is_admin() -> Result<bool, Box<dyn Error>> {
+    let url = format!("{}/user", *SOURCE_GITLAB_URL);
+    let payload = http::CLIENT
+        .get(url)
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
         .send()
         .await?
-        .error_for_status()?;
-    Ok(())
+        .error_for_status()?
+        .text()
+        .await?;
+    let user: serde_json::Value = serde_json::from_str(&payload)?;
+    Ok(user["is_admin"].as_bool().unwrap_or(false))
 }
 
-pub async fn create_target_user(
-    user: SourceUser,
-    email_mapping: &HashMap<String, String>,
-) -> Result<TargetUser, String> {
-    let user_str = format!("{:?}", user);
-    let email = match email_mapping.get(&user.username) {
-        Some(x) => x.to_string(),
-        None => format!("{}@test.com", user.username),
-    };
-    let email_str = email.to_string();
-    let spawn_result =
-        tokio::task::spawn_blocking(move || match synchronous_create_target_user(user, email) {
-            Ok(x) => Ok(x),
-            Err(err) => Err(format!(
-                "Failed to create {}\n{}\n{}.",
-                user_str, email_str, err
-            )),
-        })
-        .await;
-    spawn_result.map_err(|_| "Spawn blocking failed!".to_string())?
+// `/snippets/all` lists every public snippet on the instance, so only the
+// snippets of the given authors are kept.
+pub async fn fetch_all_source_personal_snippets(
+    usernames: &HashSet<String>,
+) -> Result<Vec<SourceSnippet>, Box<dyn Error>> {
+    let mut all_snippets = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut snippets = fetch_source_snippets(latest_page).await?;
+        latest_len = snippets.len();
+        latest_page += 1;
+        all_snippets.append(&mut snippets);
+    }
+    all_snippets.retain(|x| x.project_id.is_none() && usernames.contains(&x.author.username));
+    for snippet in all_snippets.iter_mut() {
+        let url = format!("{}/snippets/{}", *SOURCE_GITLAB_URL, snippet.id);
+        fetch_source_snippet_contents(&url, snippet).await?;
+    }
+    Ok(all_snippets)
 }
 
-pub fn synchronous_create_target_user(
-    user: SourceUser,
-    email: String,
-) -> Result<TargetUser, Box<dyn Error>> {
-    println!("Creating user {:?} with email {}...", user, email);
-    let avatar = synchronous_download_avatar(&user)?;
-    let client = reqwest::blocking::Client::new();
-    let url = format!("{}/users", *TARGET_GITLAB_URL);
-    let form = reqwest::blocking::multipart::Form::new()
-        .text("name", user.name)
-        .text("username", user.username)
-        .text("email", email)
-        .text("force_random_password", "true")
-        .text("reset_password", "true")
-        .text("skip_confirmation", "true")
-        .file("avatar", avatar)?;
+async fn fetch_source_snippets(page: u32) -> Result<Vec<SourceSnippet>, Box<dyn Error>> {
+    let url = format!("{}/snippets/all", *SOURCE_GITLAB_URL);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let snippets: Vec<SourceSnippet> = serde_json::from_str(&payload)?;
+    Ok(snippets)
+}
 
-    let response = client
-        .post(url)
-        .header("PRIVATE-TOKEN", &*TARGET_GITLAB_TOKEN)
-        .multipart(form)
-        .send()?
-        .error_for_status()?;
+async fn fetch_source_snippet_contents(
+    snippet_url: &str,
+    snippet: &mut SourceSnippet,
+) -> Result<(), Box<dyn Error>> {
+    for file in snippet.files.iter_mut() {
+        let url = format!(
+            "{}/files/{}/{}/raw",
+            snippet_url,
+            file.git_ref(),
+            file.path.replace('/', "%2F")
+        );
+        file.content = http::CLIENT
+            .get(url)
+            .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+    }
+    Ok(())
+}
 
-    let payload = response.text()?;
-    let member: TargetUser = serde_json::from_str(&payload)?;
-    Ok(member)
+pub async fn fetch_source_project_statistics(
+    project: &SourceProject,
+) -> Result<ProjectStatistics, Box<dyn Error>> {
+    let url = format!("{}/projects/{}", *SOURCE_GITLAB_URL, project.id);
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("statistics", "true")])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let project: ProjectWithStatistics = serde_json::from_str(&payload)?;
+    let statistics = project
+        .statistics
+        .ok_or("Missing statistics for source project!")?;
+    Ok(statistics)
 }
 
-pub fn synchronous_download_avatar(user: &SourceUser) -> Result<String, Box<dyn Error>> {
-    println!("Downloading avatar for {}...", user.username);
-    let client = reqwest::blocking::Client::new();
-    let response = client.get(&user.avatar_url).send()?;
-    let dir_path = "cache/avatars";
-    std::fs::create_dir_all(dir_path)?;
-    let png_path = format!("{}/{}.png", dir_path, user.username);
-    let mut file = std::fs::File::create(&png_path)?;
-    let mut content = std::io::Cursor::new(response.bytes()?);
-    std::io::copy(&mut content, &mut file)?;
-    Ok(png_path)
+pub async fn fetch_all_source_repository_refs(
+    project: &SourceProject,
+    kind: &str,
+) -> Result<Vec<RepositoryRef>, Box<dyn Error>> {
+    let mut all_refs = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut refs = fetch_source_repository_refs(project, kind, latest_page).await?;
+        latest_len = refs.len();
+        latest_page += 1;
+        all_refs.append(&mut refs);
+    }
+    Ok(all_refs)
 }
 
-pub async fn fetch_source_ci_variables(
+async fn fetch_source_repository_refs(
     project: &SourceProject,
-) -> Result<Vec<SourceVariable>, Box<dyn Error>> {
-    let url = format!("{}/projects/{}/variables", *SOURCE_GITLAB_URL, project.id);
+    kind: &str,
+    page: u32,
+) -> Result<Vec<RepositoryRef>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/repository/{}",
+        *SOURCE_GITLAB_URL, project.id, kind
+    );
     let response = http::CLIENT
         .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
         .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
         .send()
         .await?;
     if response.status().is_success() {
         let payload = &response.text().await?;
-        let variables: Vec<SourceVariable> = serde_json::from_str(payload)?;
-        Ok(variables)
+        let refs: Vec<RepositoryRef> = serde_json::from_str(payload)?;
+        Ok(refs)
     } else {
         Ok(vec![])
     }
@@ -452,20 +3732,7 @@ pub async fn fetch_source_ci_variables(
 pub async fn fetch_source_pipeline_schedules(
     project: &SourceProject,
 ) -> Result<Vec<SourcePipelineSchedule>, Box<dyn Error>> {
-    let url = format!(
-        "{}/projects/{}/pipeline_schedules",
-        *SOURCE_GITLAB_URL, project.id
-    );
-    let payload = http::CLIENT
-        .get(url)
-        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
-        .send()
-        .await?
-        .error_for_status()?
-        .text()
-        .await?;
-    let pipeline_schedules: Vec<SourcePipelineScheduleWithoutVariables> =
-        serde_json::from_str(&payload)?;
+    let pipeline_schedules = fetch_all_source_pipeline_schedule_ids(project).await?;
 
     let mut with_variables = vec![];
     for schedule in pipeline_schedules {
@@ -487,6 +3754,44 @@ pub async fn fetch_source_pipeline_schedules(
     Ok(with_variables)
 }
 
+async fn fetch_all_source_pipeline_schedule_ids(
+    project: &SourceProject,
+) -> Result<Vec<SourcePipelineScheduleWithoutVariables>, Box<dyn Error>> {
+    let mut all_pipeline_schedules = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut pipeline_schedules =
+            fetch_source_pipeline_schedule_ids(project, latest_page).await?;
+        latest_len = pipeline_schedules.len();
+        latest_page += 1;
+        all_pipeline_schedules.append(&mut pipeline_schedules);
+    }
+    Ok(all_pipeline_schedules)
+}
+
+async fn fetch_source_pipeline_schedule_ids(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourcePipelineScheduleWithoutVariables>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/pipeline_schedules",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let payload = http::CLIENT
+        .get(url)
+        .query(&[("per_page", "100"), ("page", &page.to_string())])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let pipeline_schedules: Vec<SourcePipelineScheduleWithoutVariables> =
+        serde_json::from_str(&payload)?;
+    Ok(pipeline_schedules)
+}
+
 pub async fn fetch_all_source_issues(
     project: &SourceProject,
 ) -> Result<Vec<SourceIssue>, Box<dyn Error>> {
@@ -500,6 +3805,12 @@ pub async fn fetch_all_source_issues(
         latest_page += 1;
         all_groups.append(&mut groups);
     }
+    for issue in all_groups.iter_mut() {
+        if issue.time_stats.total_time_spent != 0 {
+            issue.timelogs =
+                fetch_source_timelogs_or_log(project, Issuable::Issue, issue.iid).await;
+        }
+    }
     Ok(all_groups)
 }
 
@@ -519,6 +3830,183 @@ async fn fetch_source_issues(
     Ok(groups)
 }
 
+pub async fn fetch_source_timelogs(
+    project: &SourceProject,
+    issuable: Issuable,
+    iid: u32,
+) -> Result<Vec<Timelog>, Box<dyn Error>> {
+    let timelogs = fetch_timelogs(
+        &SOURCE_GITLAB_URL,
+        &SOURCE_GITLAB_TOKEN,
+        &project.path_with_namespace,
+        issuable,
+        iid,
+    )
+    .await?;
+    Ok(timelogs.iter().map(|x| x.to_timelog()).collect())
+}
+
+// Timelogs are an addition to the issuable, so failing to read them must not
+// abort the whole download.
+async fn fetch_source_timelogs_or_log(
+    project: &SourceProject,
+    issuable: Issuable,
+    iid: u32,
+) -> Vec<Timelog> {
+    match fetch_source_timelogs(project, issuable, iid).await {
+        Ok(timelogs) => timelogs,
+        Err(err) => {
+            println!("Error: {}", err);
+            println!(
+                "Context: \nTimelogs of {}#{}\nin\n{}\n__________",
+                issuable.url_prefix(),
+                iid,
+                project.key()
+            );
+            vec![]
+        }
+    }
+}
+
+async fn fetch_timelogs(
+    api_url: &str,
+    token: &str,
+    project_path: &str,
+    issuable: Issuable,
+    iid: u32,
+) -> Result<Vec<GraphqlTimelog>, Box<dyn Error>> {
+    let field = match issuable {
+        Issuable::Issue => "issue",
+        Issuable::MergeRequest => "mergeRequest",
+    };
+    let query = format!(
+        "query($fullPath: ID!, $iid: String!, $after: String) {{ \
+            project(fullPath: $fullPath) {{ {}(iid: $iid) {{ \
+                timelogs(first: 100, after: $after) {{ \
+                    nodes {{ id timeSpent spentAt summary user {{ username }} }} \
+                    pageInfo {{ hasNextPage endCursor }} }} }} }} }}",
+        field
+    );
+    let mut all_timelogs = vec![];
+    let mut after = serde_json::Value::Null;
+    loop {
+        let variables = serde_json::json!({
+            "fullPath": project_path,
+            "iid": iid.to_string(),
+            "after": after,
+        });
+        let data = graphql(api_url, token, &query, variables).await?;
+        let timelogs = &data["project"][field]["timelogs"];
+        if timelogs.is_null() {
+            break;
+        }
+        let mut nodes: Vec<GraphqlTimelog> = serde_json::from_value(timelogs["nodes"].clone())?;
+        all_timelogs.append(&mut nodes);
+        if timelogs["pageInfo"]["hasNextPage"] != serde_json::Value::Bool(true) {
+            break;
+        }
+        after = timelogs["pageInfo"]["endCursor"].clone();
+    }
+    Ok(all_timelogs)
+}
+
+async fn graphql(
+    api_url: &str,
+    token: &str,
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<serde_json::Value, Box<dyn Error>> {
+    let url = format!("{}/api/graphql", web_url(api_url));
+    let payload = http::CLIENT
+        .post(url)
+        .json(&serde_json::json!({ "query": query, "variables": variables }))
+        .bearer_auth(token)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let mut response: serde_json::Value = serde_json::from_str(&payload)?;
+    if let Some(errors) = response.get("errors") {
+        return Err(format!("GraphQL request failed: {}", errors).into());
+    }
+    Ok(response["data"].take())
+}
+
+fn check_mutation_errors(result: &serde_json::Value) -> Result<(), Box<dyn Error>> {
+    match result["errors"].as_array() {
+        Some(errors) if !errors.is_empty() => {
+            Err(format!("GraphQL mutation failed: {:?}", errors).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let parts: Vec<_> = [
+        (seconds / 3600, "h"),
+        (seconds % 3600 / 60, "m"),
+        (seconds % 60, "s"),
+    ]
+    .into_iter()
+    .filter(|(value, _)| *value > 0)
+    .map(|(value, unit)| format!("{}{}", value, unit))
+    .collect();
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        format!("{}{}", sign, parts.join(" "))
+    }
+}
+
+pub async fn fetch_all_source_merge_requests(
+    project: &SourceProject,
+) -> Result<Vec<SourceMergeRequest>, Box<dyn Error>> {
+    println!("Fetching all merge requests for {:?}...", project);
+    let mut all_merge_requests = vec![];
+    let mut latest_page = 1;
+    let mut latest_len = 0;
+    while latest_len == 100 || latest_page == 1 {
+        let mut merge_requests = fetch_source_merge_requests(project, latest_page).await?;
+        latest_len = merge_requests.len();
+        latest_page += 1;
+        all_merge_requests.append(&mut merge_requests);
+    }
+    for merge_request in all_merge_requests.iter_mut() {
+        if merge_request.time_stats.total_time_spent != 0 {
+            merge_request.timelogs =
+                fetch_source_timelogs_or_log(project, Issuable::MergeRequest, merge_request.iid)
+                    .await;
+        }
+    }
+    Ok(all_merge_requests)
+}
+
+async fn fetch_source_merge_requests(
+    project: &SourceProject,
+    page: u32,
+) -> Result<Vec<SourceMergeRequest>, Box<dyn Error>> {
+    let url = format!(
+        "{}/projects/{}/merge_requests",
+        *SOURCE_GITLAB_URL, project.id
+    );
+    let response = http::CLIENT
+        .get(url)
+        .query(&[
+            ("per_page", "100"),
+            ("page", &page.to_string()),
+            ("state", "all"),
+        ])
+        .header("PRIVATE-TOKEN", &*SOURCE_GITLAB_TOKEN)
+        .send()
+        .await?;
+    let payload = &response.text().await?;
+    let merge_requests: Vec<SourceMergeRequest> = serde_json::from_str(payload)?;
+    Ok(merge_requests)
+}
+
 pub async fn download_source_project_gz(status: &ExportStatus) -> Result<Response, Box<dyn Error>> {
     let url = format!(
         "{}/projects/{}/export/download",
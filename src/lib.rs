@@ -1,5 +1,8 @@
 pub mod apps;
+pub mod archive;
+pub mod crypto;
 pub mod env;
 pub mod gitlab;
 pub mod http;
+pub mod registry;
 pub mod types;
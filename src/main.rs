@@ -9,21 +9,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some("download-source-memberships") => Ok(apps::download_source_memberships().await?),
         Some("download-source-projects") => Ok(apps::download_source_projects().await?),
         Some("download-source-ci-variables") => Ok(apps::download_source_ci_variables().await?),
+        Some("download-source-triggers") => Ok(apps::download_source_triggers().await?),
+        Some("download-source-runners") => Ok(apps::download_source_runners().await?),
+        Some("download-source-integrations") => Ok(apps::download_source_integrations().await?),
+        Some("download-source-boards") => Ok(apps::download_source_boards().await?),
         Some("download-source-issues") => Ok(apps::download_source_issues().await?),
+        Some("download-source-merge-requests") => Ok(apps::download_source_merge_requests().await?),
         Some("download-source-project-metadata") => {
             Ok(apps::download_source_project_metadata().await?)
         }
         Some("download-source-pipeline-schedules") => {
             Ok(apps::download_source_pipeline_schedules().await?)
         }
+        Some("download-source-users") => Ok(apps::download_source_users().await?),
+        Some("download-source-user-keys") => Ok(apps::download_source_user_keys().await?),
+        Some("download-source-access-tokens") => Ok(apps::download_source_access_tokens().await?),
+        Some("download-source-group-labels") => Ok(apps::download_source_group_labels().await?),
+        Some("download-source-group-milestones") => {
+            Ok(apps::download_source_group_milestones().await?)
+        }
+        Some("download-source-epics") => Ok(apps::download_source_epics().await?),
+        Some("download-source-badges") => Ok(apps::download_source_badges().await?),
+        Some("download-source-snippets") => Ok(apps::download_source_snippets().await?),
+        Some("download-source-approval-rules") => Ok(apps::download_source_approval_rules().await?),
+        Some("download-source-environments") => Ok(apps::download_source_environments().await?),
         Some("create-target-users") => Ok(apps::create_target_users().await?),
+        Some("sync-target-user-states") => Ok(apps::sync_target_user_states().await?),
+        Some("create-target-user-keys") => Ok(apps::create_target_user_keys().await?),
         Some("delete-target-users") => Ok(apps::delete_target_users().await?),
+        Some("create-target-group-labels") => Ok(apps::create_target_group_labels().await?),
+        Some("create-target-group-milestones") => Ok(apps::create_target_group_milestones().await?),
+        Some("verify-target-user-emails") => Ok(apps::verify_target_user_emails().await?),
         Some("import-target-projects") => Ok(apps::import_target_projects().await?),
         Some("delete-target-projects") => Ok(apps::delete_target_projects().await?),
         Some("add-target-users-to-groups") => Ok(apps::add_target_users_to_groups().await?),
         Some("add-target-users-to-projects") => Ok(apps::add_target_users_to_projects().await?),
         Some("reassign-target-issues") => Ok(apps::reassign_target_issues().await?),
+        Some("reassign-target-merge-requests") => Ok(apps::reassign_target_merge_requests().await?),
+        Some("restore-target-time-tracking") => Ok(apps::restore_target_time_tracking().await?),
+        Some("create-target-epics") => Ok(apps::create_target_epics().await?),
+        Some("create-target-badges") => Ok(apps::create_target_badges().await?),
+        Some("create-target-snippets") => Ok(apps::create_target_snippets().await?),
+        Some("create-target-approval-rules") => Ok(apps::create_target_approval_rules().await?),
+        Some("create-target-environments") => Ok(apps::create_target_environments().await?),
+        Some("copy-target-container-images") => Ok(apps::copy_target_container_images().await?),
+        Some("copy-target-packages") => Ok(apps::copy_target_packages().await?),
+        Some("verify-target-repositories") => Ok(apps::verify_target_repositories().await?),
+        Some("sync-target-project-settings") => Ok(apps::sync_target_project_settings().await?),
         Some("create-target-ci-variables") => Ok(apps::create_target_ci_variables().await?),
+        Some("create-target-triggers") => Ok(apps::create_target_triggers().await?),
+        Some("create-target-runners") => Ok(apps::create_target_runners().await?),
+        Some("create-target-integrations") => Ok(apps::create_target_integrations().await?),
+        Some("repair-target-boards") => Ok(apps::repair_target_boards().await?),
+        Some("create-target-access-tokens") => Ok(apps::create_target_access_tokens().await?),
         Some("delete-target-pipeline-schedules") => {
             Ok(apps::delete_target_pipeline_schedules().await?)
         }
@@ -0,0 +1,342 @@
+use crate::env;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, LOCATION, WWW_AUTHENTICATE};
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+
+const MANIFEST_MEDIA_TYPES: [&str; 4] = [
+    "application/vnd.docker.distribution.manifest.v2+json",
+    "application/vnd.docker.distribution.manifest.list.v2+json",
+    "application/vnd.oci.image.manifest.v1+json",
+    "application/vnd.oci.image.index.v1+json",
+];
+
+lazy_static::lazy_static! {
+    pub static ref SOURCE_REGISTRY: Registry = Registry::from_env("SOURCE");
+    pub static ref TARGET_REGISTRY: Registry = Registry::from_env("TARGET");
+}
+
+pub struct Registry {
+    pub url: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl Registry {
+    fn from_env(prefix: &str) -> Self {
+        let url = env::load_env(&format!("{}_REGISTRY_URL", prefix));
+        let username = env::load_env(&format!("{}_REGISTRY_USERNAME", prefix));
+        let password = env::load_env(&format!("{}_REGISTRY_PASSWORD", prefix));
+        let password = if password.is_empty() {
+            env::load_env(&format!("{}_GITLAB_TOKEN", prefix))
+        } else {
+            password
+        };
+        Registry {
+            url: url.trim_end_matches('/').to_string(),
+            username,
+            password,
+        }
+    }
+}
+
+enum Authorization {
+    Anonymous,
+    Basic,
+    Bearer(String),
+}
+
+#[derive(Deserialize)]
+struct RegistryToken {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+struct Manifest {
+    media_type: String,
+    digest: Option<String>,
+    content: Vec<u8>,
+}
+
+struct Session<'a> {
+    client: &'a Client,
+    registry: &'a Registry,
+    repository: String,
+    actions: String,
+    authorization: RefCell<Authorization>,
+}
+
+impl<'a> Session<'a> {
+    fn new(
+        client: &'a Client,
+        registry: &'a Registry,
+        repository: &str,
+        actions: &str,
+    ) -> Result<Self, Box<dyn Error>> {
+        let authorization = authorize(client, registry, repository, actions)?;
+        Ok(Session {
+            client,
+            registry,
+            repository: repository.to_string(),
+            actions: actions.to_string(),
+            authorization: RefCell::new(authorization),
+        })
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        let request = self.client.request(method, url);
+        match &*self.authorization.borrow() {
+            Authorization::Anonymous => request,
+            Authorization::Basic => {
+                request.basic_auth(&self.registry.username, Some(&self.registry.password))
+            }
+            Authorization::Bearer(token) => request.bearer_auth(token),
+        }
+    }
+
+    // Bearer tokens are short-lived, so they are renewed when they expire
+    // during a long copy.
+    fn send(
+        &self,
+        method: Method,
+        url: &str,
+        build: impl Fn(RequestBuilder) -> Result<RequestBuilder, Box<dyn Error>>,
+    ) -> Result<Response, Box<dyn Error>> {
+        let response = build(self.request(method.clone(), url))?.send()?;
+        let is_bearer = matches!(*self.authorization.borrow(), Authorization::Bearer(_));
+        if response.status() != StatusCode::UNAUTHORIZED || !is_bearer {
+            return Ok(response);
+        }
+        println!("Renewing registry token for {}...", self.repository);
+        let authorization = authorize(self.client, self.registry, &self.repository, &self.actions)?;
+        *self.authorization.borrow_mut() = authorization;
+        Ok(build(self.request(method, url))?.send()?)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/v2/{}/{}", self.registry.url, self.repository, path)
+    }
+
+    fn fetch_manifest(&self, reference: &str) -> Result<Manifest, Box<dyn Error>> {
+        let url = self.url(&format!("manifests/{}", reference));
+        let response = self
+            .send(Method::GET, &url, |x| {
+                Ok(x.header(ACCEPT, MANIFEST_MEDIA_TYPES.join(", ")))
+            })?
+            .error_for_status()?;
+        let media_type = header_value(response.headers(), CONTENT_TYPE.as_str());
+        let digest = header_value(response.headers(), "Docker-Content-Digest");
+        let content = response.bytes()?.to_vec();
+        Ok(Manifest {
+            media_type: media_type.unwrap_or_default(),
+            digest,
+            content,
+        })
+    }
+
+    fn fetch_manifest_digest(&self, reference: &str) -> Result<Option<String>, Box<dyn Error>> {
+        let url = self.url(&format!("manifests/{}", reference));
+        let response = self.send(Method::HEAD, &url, |x| {
+            Ok(x.header(ACCEPT, MANIFEST_MEDIA_TYPES.join(", ")))
+        })?;
+        if response.status().is_success() {
+            Ok(header_value(response.headers(), "Docker-Content-Digest"))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn put_manifest(&self, reference: &str, manifest: &Manifest) -> Result<(), Box<dyn Error>> {
+        let url = self.url(&format!("manifests/{}", reference));
+        self.send(Method::PUT, &url, |x| {
+            Ok(x.header(CONTENT_TYPE, &manifest.media_type)
+                .body(manifest.content.clone()))
+        })?
+        .error_for_status()?;
+        Ok(())
+    }
+
+    fn has_blob(&self, digest: &str) -> Result<bool, Box<dyn Error>> {
+        let url = self.url(&format!("blobs/{}", digest));
+        let response = self.send(Method::HEAD, &url, Ok)?;
+        Ok(response.status().is_success())
+    }
+
+    fn download_blob(&self, digest: &str, path: &str) -> Result<(), Box<dyn Error>> {
+        let url = self.url(&format!("blobs/{}", digest));
+        let mut response = self.send(Method::GET, &url, Ok)?.error_for_status()?;
+        let partial_path = format!("{}.partial", path);
+        let mut file = std::fs::File::create(&partial_path)?;
+        response.copy_to(&mut file)?;
+        std::fs::rename(partial_path, path)?;
+        Ok(())
+    }
+
+    fn upload_blob(&self, digest: &str, path: &str) -> Result<(), Box<dyn Error>> {
+        let url = self.url("blobs/uploads/");
+        let response = self
+            .send(Method::POST, &url, |x| Ok(x.header(CONTENT_LENGTH, 0)))?
+            .error_for_status()?;
+        let location = header_value(response.headers(), LOCATION.as_str())
+            .ok_or("Registry did not return an upload location!")?;
+        let location = if location.starts_with('/') {
+            format!("{}{}", self.registry.url, location)
+        } else {
+            location
+        };
+        let separator = if location.contains('?') { '&' } else { '?' };
+        let url = format!("{}{}digest={}", location, separator, digest);
+
+        self.send(Method::PUT, &url, |x| {
+            let file = std::fs::File::open(path)?;
+            let length = file.metadata()?.len();
+            Ok(x.header(CONTENT_TYPE, "application/octet-stream")
+                .body(reqwest::blocking::Body::sized(file, length)))
+        })?
+        .error_for_status()?;
+        Ok(())
+    }
+}
+
+pub fn copy_image(repository: &str, tag: &str) -> Result<bool, Box<dyn Error>> {
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(900))
+        .build()?;
+    let source = Session::new(&client, &SOURCE_REGISTRY, repository, "pull")?;
+    let target = Session::new(&client, &TARGET_REGISTRY, repository, "pull,push")?;
+
+    let manifest = source.fetch_manifest(tag)?;
+    let target_digest = target.fetch_manifest_digest(tag)?;
+    if manifest.digest.is_some() && target_digest == manifest.digest {
+        println!(
+            "Skipping {}:{} which is already up to date...",
+            repository, tag
+        );
+        return Ok(false);
+    }
+
+    println!("Copying {}:{}...", repository, tag);
+    copy_manifest_contents(&source, &target, &manifest)?;
+    target.put_manifest(tag, &manifest)?;
+    println!("Copied {}:{}!", repository, tag);
+    Ok(true)
+}
+
+fn copy_manifest_contents(
+    source: &Session,
+    target: &Session,
+    manifest: &Manifest,
+) -> Result<(), Box<dyn Error>> {
+    let content: serde_json::Value = serde_json::from_slice(&manifest.content)?;
+    let children = content.get("manifests").and_then(|x| x.as_array());
+    for child in children.into_iter().flatten() {
+        let digest = child
+            .get("digest")
+            .and_then(|x| x.as_str())
+            .ok_or("Manifest list entry without a digest!")?;
+        let child_manifest = source.fetch_manifest(digest)?;
+        copy_manifest_contents(source, target, &child_manifest)?;
+        target.put_manifest(digest, &child_manifest)?;
+    }
+
+    let config = content.get("config").into_iter();
+    let layers = content.get("layers").and_then(|x| x.as_array());
+    let blobs = config.chain(layers.into_iter().flatten());
+    for blob in blobs {
+        if let Some(digest) = blob.get("digest").and_then(|x| x.as_str()) {
+            copy_blob(source, target, digest)?;
+        }
+    }
+    Ok(())
+}
+
+fn copy_blob(source: &Session, target: &Session, digest: &str) -> Result<(), Box<dyn Error>> {
+    if target.has_blob(digest)? {
+        return Ok(());
+    }
+    let dir_path = "cache/registry/blobs";
+    std::fs::create_dir_all(dir_path)?;
+    let blob_path = format!("{}/{}", dir_path, digest.replace(':', "_"));
+    if !std::path::Path::new(&blob_path).exists() {
+        println!("Downloading blob {}...", digest);
+        source.download_blob(digest, &blob_path)?;
+    }
+    println!("Uploading blob {}...", digest);
+    target.upload_blob(digest, &blob_path)?;
+    Ok(())
+}
+
+fn authorize(
+    client: &Client,
+    registry: &Registry,
+    repository: &str,
+    actions: &str,
+) -> Result<Authorization, Box<dyn Error>> {
+    let response = client.get(format!("{}/v2/", registry.url)).send()?;
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(Authorization::Anonymous);
+    }
+    let challenge = header_value(response.headers(), WWW_AUTHENTICATE.as_str()).unwrap_or_default();
+    if challenge.to_lowercase().starts_with("basic") {
+        return Ok(Authorization::Basic);
+    }
+
+    let params = parse_challenge(&challenge);
+    let realm = params
+        .get("realm")
+        .ok_or("Registry did not return a token realm!")?;
+    let mut query = vec![("scope", format!("repository:{}:{}", repository, actions))];
+    if let Some(service) = params.get("service") {
+        query.push(("service", service.to_string()));
+    }
+    let mut request = client.get(realm).query(&query);
+    if !registry.username.is_empty() {
+        request = request.basic_auth(&registry.username, Some(&registry.password));
+    }
+    let token: RegistryToken = request.send()?.error_for_status()?.json()?;
+    let token = token
+        .token
+        .or(token.access_token)
+        .ok_or("Registry did not return a token!")?;
+    Ok(Authorization::Bearer(token))
+}
+
+fn parse_challenge(challenge: &str) -> HashMap<String, String> {
+    let params = challenge
+        .split_once(' ')
+        .map(|(_, params)| params)
+        .unwrap_or_default();
+    let mut pairs = HashMap::new();
+    let mut key = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    let mut in_quotes = false;
+    for x in params.chars() {
+        match x {
+            '"' => in_quotes = !in_quotes,
+            '=' if !in_value => in_value = true,
+            ',' if !in_quotes => {
+                pairs.insert(key.trim().to_string(), value.to_string());
+                key.clear();
+                value.clear();
+                in_value = false;
+            }
+            _ if in_value => value.push(x),
+            _ => key.push(x),
+        }
+    }
+    if !key.trim().is_empty() {
+        pairs.insert(key.trim().to_string(), value);
+    }
+    pairs
+}
+
+fn header_value(headers: &reqwest::header::HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|x| x.to_str().ok())
+        .map(|x| x.to_string())
+}
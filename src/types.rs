@@ -8,6 +8,58 @@ pub struct SourceProject {
     pub path: String,
     pub path_with_namespace: String,
     pub archived: bool,
+    #[serde(flatten)]
+    pub settings: Box<ProjectSettings>,
+}
+
+// Every field is optional, since older caches and lower GitLab tiers leave
+// some out.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct ProjectSettings {
+    pub merge_method: Option<String>,
+    pub squash_option: Option<String>,
+    pub merge_pipelines_enabled: Option<bool>,
+    pub merge_trains_enabled: Option<bool>,
+    pub default_branch: Option<String>,
+    pub ci_config_path: Option<String>,
+    pub shared_runners_enabled: Option<bool>,
+    pub auto_devops_enabled: Option<bool>,
+    pub issues_access_level: Option<String>,
+    pub repository_access_level: Option<String>,
+    pub merge_requests_access_level: Option<String>,
+    pub forking_access_level: Option<String>,
+    pub wiki_access_level: Option<String>,
+    pub builds_access_level: Option<String>,
+    pub snippets_access_level: Option<String>,
+    pub pages_access_level: Option<String>,
+    pub analytics_access_level: Option<String>,
+    pub requirements_access_level: Option<String>,
+    pub security_and_compliance_access_level: Option<String>,
+    pub container_registry_access_level: Option<String>,
+    pub environments_access_level: Option<String>,
+    pub feature_flags_access_level: Option<String>,
+    pub infrastructure_access_level: Option<String>,
+    pub monitor_access_level: Option<String>,
+    pub releases_access_level: Option<String>,
+    pub container_expiration_policy: Option<ContainerExpirationPolicy>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContainerExpirationPolicy {
+    pub cadence: String,
+    pub enabled: bool,
+    pub keep_n: Option<u32>,
+    pub older_than: Option<String>,
+    pub name_regex: Option<String>,
+    pub name_regex_keep: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RejectedProjectSetting {
+    pub project: String,
+    pub setting: String,
+    pub value: serde_json::Value,
 }
 
 impl SourceProject {
@@ -37,6 +89,79 @@ pub struct SourceUser {
     pub avatar_url: String,
 }
 
+const INTERNAL_USERNAMES: [&str; 9] = [
+    "admin-bot",
+    "alert-bot",
+    "automation-bot",
+    "ghost",
+    "gitlab-llm-bot",
+    "security-bot",
+    "suggested-reviewers-bot",
+    "support-bot",
+    "visual-review-bot",
+];
+
+impl SourceUser {
+    pub fn is_internal_bot(&self) -> bool {
+        self.is_access_token_bot()
+            || self.username.starts_with("service_account_")
+            || INTERNAL_USERNAMES.contains(&self.username.to_lowercase().as_str())
+    }
+
+    pub fn is_access_token_bot(&self) -> bool {
+        let mut parts = self.username.split('_');
+        let scope = parts.next().unwrap_or_default();
+        let id = parts.next().unwrap_or_default();
+        let bot = parts.next().unwrap_or_default();
+        (scope == "project" || scope == "group")
+            && !id.is_empty()
+            && id.chars().all(|x| x.is_ascii_digit())
+            && bot == "bot"
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceUserProfile {
+    pub id: u32,
+    pub username: String,
+    pub state: String,
+    #[serde(default)]
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub job_title: Option<String>,
+    #[serde(default)]
+    pub website_url: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub external: bool,
+    #[serde(default)]
+    pub bot: bool,
+    pub is_admin: Option<bool>,
+    pub can_create_group: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceSshKey {
+    pub title: String,
+    pub key: String,
+    pub expires_at: Option<String>,
+    pub usage_type: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceGpgKey {
+    pub key: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SourceUserKeys {
+    #[serde(default)]
+    pub ssh_keys: Vec<SourceSshKey>,
+    #[serde(default)]
+    pub gpg_keys: Vec<SourceGpgKey>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct SourceMember {
     pub id: u32,
@@ -63,9 +188,110 @@ pub struct SourceIssue {
     pub title: String,
     pub author: SourceUser,
     pub assignee: Option<SourceUser>,
+    #[serde(default)]
+    pub assignees: Vec<SourceUser>,
     pub project_id: u32,
     pub labels: Vec<String>,
     pub created_at: String,
+    #[serde(default)]
+    pub time_stats: TimeStats,
+    #[serde(default)]
+    pub timelogs: Vec<Timelog>,
+}
+
+impl SourceIssue {
+    pub fn all_assignees(&self) -> Vec<SourceUser> {
+        if self.assignees.is_empty() {
+            self.assignee.iter().cloned().collect()
+        } else {
+            self.assignees.clone()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceMergeRequest {
+    pub iid: u32,
+    pub title: String,
+    pub author: SourceUser,
+    pub assignees: Vec<SourceUser>,
+    pub reviewers: Vec<SourceUser>,
+    pub project_id: u32,
+    pub created_at: String,
+    #[serde(default)]
+    pub time_stats: TimeStats,
+    #[serde(default)]
+    pub timelogs: Vec<Timelog>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct TimeStats {
+    pub time_estimate: i64,
+    pub total_time_spent: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Timelog {
+    pub username: String,
+    pub time_spent: i64,
+    pub spent_at: String,
+    pub summary: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphqlTimelog {
+    pub id: String,
+    pub time_spent: i64,
+    pub spent_at: String,
+    pub summary: Option<String>,
+    pub user: GraphqlUser,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct GraphqlUser {
+    pub username: String,
+}
+
+impl GraphqlTimelog {
+    pub fn to_timelog(&self) -> Timelog {
+        Timelog {
+            username: self.user.username.to_owned(),
+            time_spent: self.time_spent,
+            spent_at: self.spent_at.to_owned(),
+            summary: self.summary.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Issuable {
+    Issue,
+    MergeRequest,
+}
+
+impl Issuable {
+    pub fn url_prefix(&self) -> &'static str {
+        match self {
+            Self::Issue => "issues",
+            Self::MergeRequest => "merge_requests",
+        }
+    }
+
+    pub fn graphql_name(&self) -> &'static str {
+        match self {
+            Self::Issue => "Issue",
+            Self::MergeRequest => "MergeRequest",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UnrestoredTimelog {
+    pub project: String,
+    pub issuable: String,
+    pub timelog: Timelog,
+    pub reason: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -98,6 +324,7 @@ pub struct SourcePipelineSchedule {
     pub cron_timezone: String,
     pub active: bool,
     pub variables: Option<Vec<SourcePipelineVariable>>,
+    pub owner: Option<SourceUser>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -135,6 +362,461 @@ impl Membership {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceAccessToken {
+    pub id: u32,
+    pub name: String,
+    pub user_id: u32,
+    pub scopes: Vec<String>,
+    pub access_level: u32,
+    pub expires_at: Option<String>,
+    pub active: bool,
+    pub revoked: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceLabel {
+    pub id: u32,
+    pub name: String,
+    pub color: String,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceMilestone {
+    pub id: u32,
+    pub iid: u32,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: String,
+    pub start_date: Option<String>,
+    pub due_date: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceEpic {
+    pub id: u32,
+    pub iid: u32,
+    pub group_id: u32,
+    pub parent_id: Option<u32>,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub start_date_is_fixed: bool,
+    pub start_date_fixed: Option<String>,
+    #[serde(default)]
+    pub due_date_is_fixed: bool,
+    pub due_date_fixed: Option<String>,
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub issues: Vec<SourceEpicIssue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceEpicIssue {
+    pub iid: u32,
+    pub project_id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceBadge {
+    pub id: u32,
+    pub name: Option<String>,
+    pub link_url: String,
+    pub image_url: String,
+    pub kind: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceApprovalRule {
+    pub id: u32,
+    pub name: String,
+    pub rule_type: String,
+    pub approvals_required: u32,
+    pub users: Vec<SourceUser>,
+    pub groups: Vec<SourceGroup>,
+    #[serde(default)]
+    pub protected_branches: Vec<ProtectedBranch>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProtectedBranch {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceApprovalSettings {
+    pub reset_approvals_on_push: Option<bool>,
+    pub disable_overriding_approvers_per_merge_request: Option<bool>,
+    pub merge_requests_author_approval: Option<bool>,
+    pub merge_requests_disable_committers_approval: Option<bool>,
+    pub require_password_to_approve: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceProjectApprovals {
+    pub settings: Option<SourceApprovalSettings>,
+    pub rules: Vec<SourceApprovalRule>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UnmappedApprovalRule {
+    pub project: String,
+    pub rule_name: String,
+    pub rule_type: String,
+    pub skipped: bool,
+    pub unmapped_users: Vec<String>,
+    pub unmapped_groups: Vec<String>,
+    pub unmapped_branches: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceEnvironment {
+    pub id: u32,
+    pub name: String,
+    pub external_url: Option<String>,
+    pub tier: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceProtectedEnvironment {
+    pub name: String,
+    pub deploy_access_levels: Vec<SourceDeployAccessLevel>,
+    #[serde(default)]
+    pub required_approval_count: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceDeployAccessLevel {
+    pub access_level: Option<u32>,
+    pub user_id: Option<u32>,
+    pub group_id: Option<u32>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub group_full_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceFreezePeriod {
+    pub id: u32,
+    pub freeze_start: String,
+    pub freeze_end: String,
+    pub cron_timezone: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceProjectEnvironments {
+    pub environments: Vec<SourceEnvironment>,
+    pub protected_environments: Vec<SourceProtectedEnvironment>,
+    pub freeze_periods: Vec<SourceFreezePeriod>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UnmappedDeployAccessLevel {
+    pub project: String,
+    pub environment: String,
+    pub username: Option<String>,
+    pub group_full_path: Option<String>,
+    pub fallback_access_level: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceRegistryRepository {
+    pub id: u32,
+    pub name: String,
+    pub path: String,
+    pub location: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceRegistryTag {
+    pub name: String,
+    pub path: String,
+    pub location: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourcePackage {
+    pub id: u32,
+    pub name: String,
+    pub version: Option<String>,
+    pub package_type: String,
+}
+
+impl SourcePackage {
+    pub fn key(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}/{}@{}", self.package_type, self.name, version),
+            None => format!("{}/{}", self.package_type, self.name),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourcePackageFile {
+    pub id: u32,
+    pub file_name: String,
+    pub size: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceBoard {
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub hide_backlog_list: bool,
+    #[serde(default)]
+    pub hide_closed_list: bool,
+    pub milestone: Option<BoardMilestone>,
+    pub assignee: Option<BoardAssignee>,
+    #[serde(default)]
+    pub labels: Vec<BoardLabel>,
+    pub weight: Option<i32>,
+    pub lists: Vec<SourceBoardList>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceBoardList {
+    pub id: u32,
+    pub label: Option<BoardLabel>,
+    pub assignee: Option<BoardAssignee>,
+    pub milestone: Option<BoardMilestone>,
+    pub position: Option<u32>,
+}
+
+impl SourceBoardList {
+    pub fn key(&self) -> Option<String> {
+        board_list_key(&self.label, &self.assignee, &self.milestone)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BoardLabel {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BoardAssignee {
+    pub username: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BoardMilestone {
+    pub title: String,
+}
+
+fn board_list_key(
+    label: &Option<BoardLabel>,
+    assignee: &Option<BoardAssignee>,
+    milestone: &Option<BoardMilestone>,
+) -> Option<String> {
+    match (label, assignee, milestone) {
+        (Some(x), _, _) => Some(format!("label:{}", x.name)),
+        (_, Some(x), _) => Some(format!("assignee:{}", x.username)),
+        (_, _, Some(x)) => Some(format!("milestone:{}", x.title)),
+        _ => None,
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BoardRepair {
+    pub scope: String,
+    pub board: String,
+    pub created_board: bool,
+    pub updated_scope: bool,
+    pub created_lists: Vec<String>,
+    pub unmapped_lists: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceIntegrationWithoutProperties {
+    pub slug: String,
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetIntegration {
+    pub slug: String,
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceIntegration {
+    pub slug: String,
+    pub title: String,
+    pub active: bool,
+    #[serde(default)]
+    pub properties: HashMap<String, serde_json::Value>,
+    #[serde(flatten)]
+    pub settings: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IntegrationSecrets {
+    pub project: String,
+    pub integration: String,
+    pub secret_fields: Vec<String>,
+    pub created: bool,
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceRunnerWithoutDetails {
+    pub id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceRunner {
+    pub id: u32,
+    pub description: Option<String>,
+    pub runner_type: String,
+    pub tag_list: Vec<String>,
+    pub run_untagged: bool,
+    pub locked: bool,
+    pub access_level: String,
+    #[serde(default)]
+    pub paused: bool,
+    pub maximum_timeout: Option<u32>,
+    #[serde(default)]
+    pub is_shared: bool,
+    #[serde(default)]
+    pub groups: Vec<SourceRunnerGroup>,
+    #[serde(default)]
+    pub projects: Vec<SourceRunnerProject>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceRunnerGroup {
+    pub id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceRunnerProject {
+    pub id: u32,
+    pub path_with_namespace: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreatedRunner {
+    pub source_id: u32,
+    pub id: u32,
+    pub description: String,
+    pub runner_type: String,
+    pub scope: String,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceTrigger {
+    pub id: u32,
+    pub description: String,
+    pub token: String,
+    pub owner: Option<SourceUser>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TriggerTokenMapping {
+    pub project: String,
+    pub description: String,
+    pub source_token: String,
+    pub target_token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceSnippet {
+    pub id: u32,
+    pub title: String,
+    pub description: Option<String>,
+    pub visibility: String,
+    pub author: SourceUser,
+    pub project_id: Option<u32>,
+    #[serde(default)]
+    pub files: Vec<SourceSnippetFile>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SourceSnippetFile {
+    pub path: String,
+    pub raw_url: String,
+    #[serde(default)]
+    pub content: String,
+}
+
+impl SourceSnippetFile {
+    pub fn git_ref(&self) -> String {
+        self.raw_url
+            .split_once("/raw/")
+            .and_then(|(_, x)| x.strip_suffix(&format!("/{}", self.path)))
+            .unwrap_or("main")
+            .to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProjectStatistics {
+    pub commit_count: u64,
+    pub repository_size: u64,
+    pub lfs_objects_size: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProjectWithStatistics {
+    pub id: u32,
+    pub path_with_namespace: String,
+    pub statistics: Option<ProjectStatistics>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RepositoryRef {
+    pub name: String,
+    pub commit: RepositoryCommit,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RepositoryCommit {
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RepositoryVerification {
+    pub project: String,
+    pub passed: bool,
+    pub error: Option<String>,
+    pub mismatched_branches: Vec<String>,
+    pub mismatched_tags: Vec<String>,
+    pub source_commit_count: u64,
+    pub target_commit_count: u64,
+    pub source_repository_size: u64,
+    pub target_repository_size: u64,
+    pub source_lfs_objects_size: u64,
+    pub target_lfs_objects_size: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportMember {
+    pub user: Option<ExportMemberUser>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportMemberUser {
+    pub id: u32,
+    pub username: String,
+    pub public_email: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UnmappedContributor {
+    pub project: String,
+    pub source_user_id: u32,
+    pub username: Option<String>,
+    pub public_email: Option<String>,
+    pub reason: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ExportStatus {
     pub id: u32,
@@ -146,7 +828,22 @@ pub type CachedProjectMetadata = HashMap<u32, SourceProject>;
 pub type CachedCiVariables = HashMap<String, Vec<SourceVariable>>;
 pub type CachedMemberships = HashMap<String, HashMap<String, Vec<SourceMember>>>;
 pub type CachedIssues = HashMap<String, Vec<SourceIssue>>;
+pub type CachedMergeRequests = HashMap<String, Vec<SourceMergeRequest>>;
 pub type CachedPipelineSchedules = HashMap<String, Vec<SourcePipelineSchedule>>;
+pub type CachedAccessTokens = HashMap<String, HashMap<String, Vec<SourceAccessToken>>>;
+pub type CachedGroupLabels = HashMap<String, Vec<SourceLabel>>;
+pub type CachedGroupMilestones = HashMap<String, Vec<SourceMilestone>>;
+pub type CachedEpics = HashMap<String, Vec<SourceEpic>>;
+pub type CachedBadges = HashMap<String, HashMap<String, Vec<SourceBadge>>>;
+pub type CachedApprovals = HashMap<String, SourceProjectApprovals>;
+pub type CachedEnvironments = HashMap<String, SourceProjectEnvironments>;
+pub type CachedUsers = HashMap<String, SourceUserProfile>;
+pub type CachedUserKeys = HashMap<String, SourceUserKeys>;
+pub type CachedBoards = HashMap<String, HashMap<String, Vec<SourceBoard>>>;
+pub type CachedIntegrations = HashMap<String, Vec<SourceIntegration>>;
+pub type CachedRunners = HashMap<String, HashMap<String, Vec<SourceRunner>>>;
+pub type CachedTriggers = HashMap<String, Vec<SourceTrigger>>;
+pub type CachedSnippets = HashMap<String, HashMap<String, Vec<SourceSnippet>>>;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TargetUser {
@@ -154,12 +851,51 @@ pub struct TargetUser {
     pub name: String,
     pub username: String,
     pub email: String,
+    #[serde(default)]
+    pub identities: Vec<UserIdentity>,
+    #[serde(default)]
+    pub custom_attributes: Vec<CustomAttribute>,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub external: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UserIdentity {
+    pub provider: String,
+    pub extern_uid: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustomAttribute {
+    pub key: String,
+    pub value: String,
 }
 
 impl TargetUser {
     pub fn key(&self) -> String {
         self.username.to_string()
     }
+
+    pub fn custom_attribute(&self, key: &str) -> Option<&str> {
+        self.custom_attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetSshKey {
+    pub id: u32,
+    pub key: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetGpgKey {
+    pub id: u32,
+    pub key: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -194,4 +930,209 @@ impl TargetGroup {
 pub struct TargetPipelineSchedule {
     pub id: u32,
     pub description: String,
+    #[serde(default)]
+    pub variables: Vec<TargetPipelineVariable>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetPipelineVariable {
+    pub variable_type: String,
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetLabel {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetMilestone {
+    pub id: u32,
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetEpic {
+    pub id: u32,
+    pub iid: u32,
+    pub group_id: u32,
+    pub title: String,
+    pub created_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetIssue {
+    pub id: u32,
+    pub iid: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetIssuable {
+    pub id: u32,
+    pub iid: u32,
+    pub time_stats: TimeStats,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetBadge {
+    pub id: u32,
+    pub link_url: String,
+    pub image_url: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetApprovalRule {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetEnvironment {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetProtectedEnvironment {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetFreezePeriod {
+    pub id: u32,
+    pub freeze_start: String,
+    pub freeze_end: String,
+    pub cron_timezone: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetBoard {
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub hide_backlog_list: bool,
+    #[serde(default)]
+    pub hide_closed_list: bool,
+    pub milestone: Option<TargetBoardMilestone>,
+    pub assignee: Option<TargetBoardAssignee>,
+    #[serde(default)]
+    pub labels: Vec<BoardLabel>,
+    pub weight: Option<i32>,
+    pub lists: Vec<TargetBoardList>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetBoardMilestone {
+    pub id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetBoardAssignee {
+    pub id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetBoardList {
+    pub id: u32,
+    pub label: Option<BoardLabel>,
+    pub assignee: Option<BoardAssignee>,
+    pub milestone: Option<BoardMilestone>,
+    pub position: Option<u32>,
+}
+
+impl TargetBoardList {
+    pub fn key(&self) -> Option<String> {
+        board_list_key(&self.label, &self.assignee, &self.milestone)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetRunner {
+    pub id: u32,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetTrigger {
+    pub id: u32,
+    pub description: String,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetSnippet {
+    pub id: u32,
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetPackage {
+    pub id: u32,
+    pub name: String,
+    pub version: Option<String>,
+    pub package_type: String,
+}
+
+impl TargetPackage {
+    pub fn key(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}/{}@{}", self.package_type, self.name, version),
+            None => format!("{}/{}", self.package_type, self.name),
+        }
+    }
+}
+
+pub enum TargetMembership {
+    Group(TargetGroup),
+    Project(TargetProject),
+}
+
+impl TargetMembership {
+    pub fn url_prefix(&self) -> &'static str {
+        match self {
+            Self::Group(_) => "groups",
+            Self::Project(_) => "projects",
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        match self {
+            Self::Group(x) => x.id,
+            Self::Project(x) => x.id,
+        }
+    }
+
+    pub fn key(&self) -> String {
+        match self {
+            Self::Group(x) => x.key(),
+            Self::Project(x) => x.key(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TargetAccessToken {
+    pub id: u32,
+    pub name: String,
+    pub active: bool,
+    pub token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreatedAccessToken {
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub access_level: u32,
+    pub expires_at: Option<String>,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccessTokenVariableReference {
+    pub project: String,
+    pub variable_key: String,
+    pub token_names: Vec<String>,
+    pub unknown_token: bool,
 }